@@ -0,0 +1,139 @@
+use crate::*;
+
+use std::collections::BTreeSet;
+
+/// Strongly connected components of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// Components in topological order: every edge between two components
+    /// goes from an earlier one to a later one.
+    pub components: Vec<BTreeSet<String>>,
+    /// Index of the component every node belongs to.
+    pub component_of: HashMap<String, usize>,
+}
+
+impl Components {
+    /// Get name of `i`-th component made of its nodes, e.g. `{A B C}`.
+    pub fn name(&self, i: usize) -> String {
+        let nodes = self.components[i].iter().cloned().collect::<Vec<_>>();
+        format!("{{{}}}", nodes.join(" "))
+    }
+}
+
+/// Find strongly connected components using Tarjan algorithm. Works in
+/// linear time and does not recurse, so big graphs are fine too. In not
+/// oriented graph these are just connected components.
+pub fn strongly_connected(gr: &Graph) -> Components {
+    let al = gr.get_adjacency_list();
+    let nodes = gr.get_nodes();
+    let index_of = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.as_str(), i))
+        .collect::<HashMap<_, _>>();
+    let adjacent = nodes
+        .iter()
+        .map(|node| {
+            let mut to = al[node]
+                .keys()
+                .map(|n| index_of[n.as_str()])
+                .collect::<Vec<_>>();
+            to.sort_unstable();
+            to
+        })
+        .collect::<Vec<_>>();
+
+    const UNVISITED: usize = usize::MAX;
+    let mut index = vec![UNVISITED; nodes.len()];
+    let mut low_link = vec![0; nodes.len()];
+    let mut on_stack = vec![false; nodes.len()];
+    let mut stack = Vec::new();
+    let mut found = Vec::<BTreeSet<String>>::new();
+    let mut counter = 0;
+
+    for root in 0..nodes.len() {
+        if index[root] != UNVISITED {
+            continue;
+        }
+
+        // Nodes being visited together with position of the next neighbour
+        // to look at, instead of recursive calls
+        let mut calls = vec![(root, 0)];
+        index[root] = counter;
+        low_link[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((v, next)) = calls.last_mut() {
+            let v = *v;
+            if let Some(&w) = adjacent[v].get(*next) {
+                *next += 1;
+                if index[w] == UNVISITED {
+                    index[w] = counter;
+                    low_link[w] = counter;
+                    counter += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    calls.push((w, 0));
+                } else if on_stack[w] {
+                    low_link[v] = low_link[v].min(index[w]);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some((parent, _)) = calls.last() {
+                low_link[*parent] = low_link[*parent].min(low_link[v]);
+            }
+            if low_link[v] == index[v] {
+                let mut component = BTreeSet::new();
+                loop {
+                    let w = stack.pop().unwrap();
+                    on_stack[w] = false;
+                    component.insert(nodes[w].to_string());
+                    if w == v {
+                        break;
+                    }
+                }
+                found.push(component);
+            }
+        }
+    }
+
+    // Tarjan algorithm finds components in reverse topological order
+    found.reverse();
+    let component_of = found
+        .iter()
+        .enumerate()
+        .flat_map(|(i, c)| c.iter().map(move |node| (node.to_string(), i)))
+        .collect();
+
+    Components {
+        components: found,
+        component_of,
+    }
+}
+
+/// Build oriented graph of components, connecting two components if there is
+/// an edge between their nodes in `gr`. Component is named after its nodes,
+/// e.g. `{A B C}`. Resulting graph has no cycles.
+pub fn condensation(gr: &Graph) -> GraphResult<Graph> {
+    let components = strongly_connected(gr);
+    let names = (0..components.components.len())
+        .map(|i| components.name(i))
+        .collect::<Vec<_>>();
+
+    let mut condensed = Graph::new(false, true);
+    for name in &names {
+        condensed.push_node(name.to_string())?;
+    }
+    for (from, to, _) in gr.get_edges() {
+        let (from, to) = (components.component_of[&from], components.component_of[&to]);
+        if from != to {
+            condensed.push_edge(names[from].to_string(), names[to].to_string(), None)?;
+        }
+    }
+
+    Ok(condensed)
+}
@@ -1,3 +1,6 @@
+pub mod components;
 pub mod mst;
+pub mod paths;
+pub mod trace;
 pub mod traversals;
 pub mod weighted;
@@ -1,8 +1,14 @@
+use crate::algorithms::trace::Event;
 use crate::*;
 
 use std::collections::HashSet;
 
 pub fn kruskal(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
+    kruskal_traced(gr, &mut Vec::new())
+}
+
+/// Same as [`kruskal`], but also records every considered edge to `trace`.
+pub fn kruskal_traced(gr: &Graph, trace: &mut Vec<Event>) -> GraphResult<Vec<EdgeWeighted>> {
     check_if_applicable(gr)?;
 
     let mut edges = gr
@@ -18,7 +24,16 @@ pub fn kruskal(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
     }
 
     let mut mst = Vec::<EdgeWeighted>::new();
+    let mut considered = HashSet::<(String, String)>::new();
     for edge in edges {
+        // Both directions of every edge are listed, report each edge once
+        let key = if edge.0 < edge.1 {
+            (edge.0.to_string(), edge.1.to_string())
+        } else {
+            (edge.1.to_string(), edge.0.to_string())
+        };
+        let is_new = considered.insert(key);
+
         if tree_id[&edge.0] != tree_id[&edge.1] {
             let old_id = tree_id[&edge.0];
             let new_id = tree_id[&edge.1];
@@ -28,7 +43,18 @@ pub fn kruskal(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
                 }
             }
 
+            trace.push(Event::Accepted {
+                from: edge.0.to_string(),
+                to: edge.1.to_string(),
+                weight: Some(edge.2),
+            });
             mst.push(edge);
+        } else if is_new {
+            trace.push(Event::Rejected {
+                from: edge.0,
+                to: edge.1,
+                weight: Some(edge.2),
+            });
         }
     }
 
@@ -36,6 +62,12 @@ pub fn kruskal(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
 }
 
 pub fn prim(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
+    prim_traced(gr, &mut Vec::new())
+}
+
+/// Same as [`prim`], but also records nodes and edges added to the tree to
+/// `trace`.
+pub fn prim_traced(gr: &Graph, trace: &mut Vec<Event>) -> GraphResult<Vec<EdgeWeighted>> {
     check_if_applicable(gr)?;
 
     let mut mst = Vec::<EdgeWeighted>::new();
@@ -54,6 +86,10 @@ pub fn prim(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
         available_edges.insert((start_element.to_string(), to.to_string(), weight.unwrap()));
     }
     not_used_nodes.remove(&start_element);
+    trace.push(Event::Settled {
+        node: start_element.to_string(),
+        distance: None,
+    });
     used_nodes.insert(start_element);
 
     while !not_used_nodes.is_empty() {
@@ -73,6 +109,15 @@ pub fn prim(gr: &Graph) -> GraphResult<Vec<EdgeWeighted>> {
         if !used_nodes.contains(&new_node) {
             used_nodes.insert(new_node.clone());
             not_used_nodes.remove(&new_node);
+            trace.push(Event::Accepted {
+                from: next_connection.0.to_string(),
+                to: new_node.to_string(),
+                weight: Some(next_connection.2),
+            });
+            trace.push(Event::Settled {
+                node: new_node.to_string(),
+                distance: None,
+            });
             mst.push(next_connection.clone());
         }
         for connection in &gr.get_adjacency_list()[&new_node] {
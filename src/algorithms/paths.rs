@@ -0,0 +1,157 @@
+use crate::*;
+
+/// Limits for [`simple_paths`], `None` means no limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathLimits {
+    /// Largest number of edges in a path.
+    pub max_edges: Option<usize>,
+    /// Largest total weight of a path.
+    pub max_weight: Option<EdgeWeight>,
+    /// Largest number of paths to find.
+    pub max_count: Option<usize>,
+}
+
+/// Lazy iterator over simple paths between two nodes, see [`simple_paths`].
+#[derive(Debug, Clone)]
+pub struct SimplePaths<'a> {
+    gr: &'a Graph,
+    from: &'a String,
+    to: &'a String,
+    limits: PathLimits,
+    /// Weight limit can stop a path early only if no edge makes it lighter.
+    prune_by_weight: bool,
+    /// Path being extended, without the end.
+    path: Vec<&'a String>,
+    /// Weight of path up to every its node. It is wide enough for any path,
+    /// so that only weights of whole paths have to fit into a number.
+    weights: Vec<i64>,
+    on_path: HashSet<&'a String>,
+    /// Neighbours of every node of the path and position of the next one
+    /// to look at.
+    stack: Vec<(Vec<&'a String>, usize)>,
+    found: usize,
+}
+
+/// Get paths from `from` to `to` which visit every node at most once,
+/// together with their weights. Edges of not weighted graph weigh 1.
+///
+/// Paths are found one by one in alphabetical order of their nodes, so
+/// iteration can be stopped at any moment without looking at the rest of
+/// them, which may be a lot.
+pub fn simple_paths<'a>(
+    gr: &'a Graph,
+    from: &str,
+    to: &str,
+    limits: PathLimits,
+) -> GraphResult<SimplePaths<'a>> {
+    let node = |name: &str| {
+        gr.adjacency_list
+            .get_key_value(name)
+            .map(|(node, _)| node)
+            .ok_or_else(|| GraphError {
+                msg: format!("Node {name:?} does not exist"),
+            })
+    };
+    let (from, to) = (node(from)?, node(to)?);
+    let prune_by_weight = gr
+        .adjacency_list
+        .values()
+        .flat_map(|connections| connections.values())
+        .all(|w| w.unwrap_or(1) >= 0);
+
+    Ok(SimplePaths {
+        gr,
+        from,
+        to,
+        limits,
+        prune_by_weight,
+        path: vec![from],
+        weights: vec![0],
+        on_path: HashSet::from([from]),
+        stack: vec![(neighbours(gr, from), 0)],
+        found: 0,
+    })
+}
+
+fn neighbours<'a>(gr: &'a Graph, node: &String) -> Vec<&'a String> {
+    let mut connections = gr.adjacency_list[node].keys().collect::<Vec<_>>();
+    connections.sort_unstable();
+    connections
+}
+
+impl SimplePaths<'_> {
+    fn record(&mut self, end: Option<&String>, weight: EdgeWeight) -> (Vec<String>, EdgeWeight) {
+        self.found += 1;
+        let mut path = self.path.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        path.extend(end.cloned());
+        (path, weight)
+    }
+}
+
+impl Iterator for SimplePaths<'_> {
+    type Item = (Vec<String>, EdgeWeight);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.limits.max_count.is_some_and(|max| self.found >= max) {
+            return None;
+        }
+
+        // The only simple path from a node to itself is the node alone
+        if self.from == self.to {
+            self.stack.clear();
+            return match self.found {
+                0 if self.limits.max_weight.is_none_or(|max| max >= 0) => {
+                    Some(self.record(None, 0))
+                }
+                _ => None,
+            };
+        }
+
+        while let Some((connections, next)) = self.stack.last_mut() {
+            let Some(&node) = connections.get(*next) else {
+                self.stack.pop();
+                self.weights.pop();
+                if let Some(node) = self.path.pop() {
+                    self.on_path.remove(node);
+                }
+                continue;
+            };
+            *next += 1;
+            if self.on_path.contains(node) {
+                continue;
+            }
+
+            let last = *self.path.last().unwrap();
+            let edge = self.gr.adjacency_list[last][node].unwrap_or(1);
+            let weight = self.weights.last().unwrap() + i64::from(edge);
+            let edges = self.path.len();
+            let too_long = self.limits.max_edges.is_some_and(|max| edges > max);
+            let too_heavy = self
+                .limits
+                .max_weight
+                .is_some_and(|max| weight > i64::from(max));
+            if node == self.to {
+                // Paths whose weight does not fit into a number are skipped
+                match EdgeWeight::try_from(weight) {
+                    Ok(weight) if !too_long && !too_heavy => {
+                        return Some(self.record(Some(node), weight))
+                    }
+                    _ => continue,
+                }
+            }
+
+            // Going further makes path only longer, and heavier if weights
+            // are not negative
+            let no_edges_left = self.limits.max_edges.is_some_and(|max| edges >= max);
+            if no_edges_left || too_heavy && self.prune_by_weight {
+                continue;
+            }
+            self.path.push(node);
+            self.weights.push(weight);
+            self.on_path.insert(node);
+            self.stack.push((neighbours(self.gr, node), 0));
+        }
+
+        None
+    }
+}
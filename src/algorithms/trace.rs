@@ -0,0 +1,117 @@
+use crate::*;
+
+/// Single step of an algorithm, recorded by `*_traced` functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Node is done with: its distance is final (Dijkstra), it has been
+    /// added to the tree (Prim) or visited (DFS, BFS).
+    Settled {
+        node: String,
+        distance: Option<EdgeWeight>,
+    },
+    /// Shorter path to `to` has been found through `from`.
+    Relaxed {
+        from: String,
+        to: String,
+        distance: EdgeWeight,
+    },
+    /// Edge has been taken into the tree.
+    Accepted {
+        from: String,
+        to: String,
+        weight: Option<EdgeWeight>,
+    },
+    /// Edge has been looked at and thrown away.
+    Rejected {
+        from: String,
+        to: String,
+        weight: Option<EdgeWeight>,
+    },
+}
+
+/// Distances known after an iteration of an algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Iteration {
+    /// What has been done on this iteration, e.g. `round 2`.
+    pub label: String,
+    pub distances: HashMap<String, Option<EdgeWeight>>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let weight = |w: &Option<EdgeWeight>| w.map_or(String::new(), |w| format!(" ({w})"));
+        match self {
+            Event::Settled {
+                node,
+                distance: Some(d),
+            } => write!(f, "settle {node} at distance {d}"),
+            Event::Settled { node, .. } => write!(f, "settle {node}"),
+            Event::Relaxed { from, to, distance } => {
+                write!(
+                    f,
+                    "relax {from} -> {to}: distance of {to} is now {distance}"
+                )
+            }
+            Event::Accepted {
+                from,
+                to,
+                weight: w,
+            } => {
+                write!(f, "accept edge {from} - {to}{}", weight(w))
+            }
+            Event::Rejected {
+                from,
+                to,
+                weight: w,
+            } => {
+                write!(f, "reject edge {from} - {to}{}", weight(w))
+            }
+        }
+    }
+}
+
+/// Turn trace of [`dijkstra_traced`](crate::algorithms::weighted::dijkstra_traced)
+/// into distances known after every settled node has been processed. Start
+/// is the first settled node, so it has zero distance from the beginning.
+pub fn dijkstra_iterations(gr: &Graph, trace: &[Event]) -> Vec<Iteration> {
+    let mut distances = gr
+        .get_nodes()
+        .into_iter()
+        .map(|n| (n, None))
+        .collect::<HashMap<_, _>>();
+    if let Some(Event::Settled { node, distance }) = trace.first() {
+        distances.insert(node.to_string(), *distance);
+    }
+    let mut iterations = vec![Iteration {
+        label: "initial".to_string(),
+        distances: distances.clone(),
+    }];
+
+    let mut settled: Option<&String> = None;
+    for event in trace {
+        match event {
+            Event::Settled { node, distance } => {
+                if let Some(prev) = settled {
+                    iterations.push(Iteration {
+                        label: format!("settle {prev}"),
+                        distances: distances.clone(),
+                    });
+                }
+                distances.insert(node.to_string(), *distance);
+                settled = Some(node);
+            }
+            Event::Relaxed { to, distance, .. } => {
+                distances.insert(to.to_string(), Some(*distance));
+            }
+            _ => (),
+        }
+    }
+    if let Some(prev) = settled {
+        iterations.push(Iteration {
+            label: format!("settle {prev}"),
+            distances,
+        });
+    }
+
+    iterations
+}
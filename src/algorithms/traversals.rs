@@ -1,107 +1,542 @@
+use crate::algorithms::trace::Event;
 use crate::*;
 
+use std::collections::{BTreeSet, VecDeque};
+
+/// Neighbours of the node in alphabetical order.
+fn sorted_neighbours<'a>(gr: &'a Graph, node: &String) -> Vec<&'a String> {
+    let mut connections = gr.adjacency_list[node].keys().collect::<Vec<_>>();
+    connections.sort_unstable();
+    connections
+}
+
+/// Lazy DFS (Depth-First Search), returning nodes in order of visiting.
+///
+/// Uses a stack instead of recursion, so any deep graph can be traversed.
+/// Traversal can be stopped at any moment and continued later, and
+/// [`Dfs::restart`] continues it from another node, skipping nodes visited
+/// before.
+#[derive(Debug, Clone)]
+pub struct Dfs<'a> {
+    gr: &'a Graph,
+    visited: HashSet<&'a String>,
+    /// Nodes being visited together with their neighbours and position of
+    /// the next neighbour to look at.
+    stack: Vec<(&'a String, Vec<&'a String>, usize)>,
+    /// Nodes to start from when the stack is over.
+    starts: VecDeque<&'a String>,
+    /// Every node to start from after `starts`, for [`Dfs::forest`].
+    roots: Option<std::vec::IntoIter<&'a String>>,
+    parent: Option<&'a String>,
+}
+
+impl<'a> Dfs<'a> {
+    /// Visit nodes reachable from `start`. Nothing is visited if there is no
+    /// such node.
+    pub fn new(gr: &'a Graph, start: &str) -> Self {
+        let mut dfs = Self {
+            gr,
+            visited: HashSet::new(),
+            stack: Vec::new(),
+            starts: VecDeque::new(),
+            roots: None,
+            parent: None,
+        };
+        dfs.restart(start);
+        dfs
+    }
+
+    /// Visit every node of the graph: when reachable nodes are over, start
+    /// again from the first unvisited node in alphabetical order.
+    pub fn forest(gr: &'a Graph) -> Self {
+        let mut roots = gr.adjacency_list.keys().collect::<Vec<_>>();
+        roots.sort_unstable();
+        Self {
+            gr,
+            visited: HashSet::new(),
+            stack: Vec::new(),
+            starts: VecDeque::new(),
+            roots: Some(roots.into_iter()),
+            parent: None,
+        }
+    }
+
+    /// Continue traversal from `node` once nodes reachable from previous
+    /// starts are visited. Returns false if there is no such node or it is
+    /// already visited.
+    pub fn restart(&mut self, node: &str) -> bool {
+        match self.gr.adjacency_list.get_key_value(node) {
+            Some((node, _)) if !self.visited.contains(node) => {
+                self.starts.push_back(node);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Node from which the last returned node was reached, or `None` if it
+    /// is a start of traversal.
+    pub fn parent(&self) -> Option<&'a String> {
+        self.parent
+    }
+
+    pub fn is_visited(&self, node: &str) -> bool {
+        self.visited.contains(&node.to_string())
+    }
+
+    fn visit(&mut self, node: &'a String, parent: Option<&'a String>) -> String {
+        self.visited.insert(node);
+        self.stack.push((node, sorted_neighbours(self.gr, node), 0));
+        self.parent = parent;
+        node.to_string()
+    }
+}
+
+impl Iterator for Dfs<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            while let Some((node, connections, next)) = self.stack.last_mut() {
+                let node = *node;
+                match connections.get(*next) {
+                    Some(&to) => {
+                        *next += 1;
+                        if !self.visited.contains(to) {
+                            return Some(self.visit(to, Some(node)));
+                        }
+                    }
+                    None => {
+                        self.stack.pop();
+                    }
+                }
+            }
+
+            let root = match self.starts.pop_front() {
+                Some(root) => root,
+                None => self.roots.as_mut()?.next()?,
+            };
+            if !self.visited.contains(root) {
+                return Some(self.visit(root, None));
+            }
+        }
+    }
+}
+
+/// Lazy BFS (Breadth-First Search), returning nodes in order of visiting.
+///
+/// Like [`Dfs`], it can be stopped, continued and restarted from another
+/// node.
+#[derive(Debug, Clone)]
+pub struct Bfs<'a> {
+    gr: &'a Graph,
+    /// Nodes which are already queued or visited.
+    seen: HashSet<&'a String>,
+    /// Nodes to visit together with nodes they were reached from.
+    queue: VecDeque<(&'a String, Option<&'a String>)>,
+    /// Nodes to start from when the queue is over.
+    starts: VecDeque<&'a String>,
+    /// Every node to start from after `starts`, for [`Bfs::forest`].
+    roots: Option<std::vec::IntoIter<&'a String>>,
+    parent: Option<&'a String>,
+}
+
+impl<'a> Bfs<'a> {
+    /// Visit nodes reachable from `start`. Nothing is visited if there is no
+    /// such node.
+    pub fn new(gr: &'a Graph, start: &str) -> Self {
+        let mut bfs = Self {
+            gr,
+            seen: HashSet::new(),
+            queue: VecDeque::new(),
+            starts: VecDeque::new(),
+            roots: None,
+            parent: None,
+        };
+        bfs.restart(start);
+        bfs
+    }
+
+    /// Visit every node of the graph: when reachable nodes are over, start
+    /// again from the first unvisited node in alphabetical order.
+    pub fn forest(gr: &'a Graph) -> Self {
+        let mut roots = gr.adjacency_list.keys().collect::<Vec<_>>();
+        roots.sort_unstable();
+        Self {
+            gr,
+            seen: HashSet::new(),
+            queue: VecDeque::new(),
+            starts: VecDeque::new(),
+            roots: Some(roots.into_iter()),
+            parent: None,
+        }
+    }
+
+    /// Continue traversal from `node` once nodes reachable from previous
+    /// starts are visited. Returns false if there is no such node or it is
+    /// already visited.
+    pub fn restart(&mut self, node: &str) -> bool {
+        match self.gr.adjacency_list.get_key_value(node) {
+            Some((node, _)) if !self.seen.contains(node) => {
+                self.starts.push_back(node);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Node from which the last returned node was reached, or `None` if it
+    /// is a start of traversal.
+    pub fn parent(&self) -> Option<&'a String> {
+        self.parent
+    }
+
+    /// Check if node is visited or is going to be visited soon.
+    pub fn is_seen(&self, node: &str) -> bool {
+        self.seen.contains(&node.to_string())
+    }
+}
+
+impl Iterator for Bfs<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while self.queue.is_empty() {
+            let root = match self.starts.pop_front() {
+                Some(root) => root,
+                None => self.roots.as_mut()?.next()?,
+            };
+            if self.seen.insert(root) {
+                self.queue.push_back((root, None));
+            }
+        }
+
+        let (node, parent) = self.queue.pop_front()?;
+        for to in sorted_neighbours(self.gr, node) {
+            if self.seen.insert(to) {
+                self.queue.push_back((to, Some(node)));
+            }
+        }
+        self.parent = parent;
+        Some(node.to_string())
+    }
+}
+
 /// Get vector of nodes in DFS (Depth-First Search) order.
 pub fn dfs(gr: &Graph, start: String) -> Vec<String> {
-    fn dfs_inner(
-        al: &HashMap<String, HashMap<String, Option<EdgeWeight>>>,
-        visited: &mut HashSet<String>,
-        path: &mut Vec<String>,
-        cur_node: &String,
-    ) -> Vec<String> {
-        visited.insert(cur_node.to_string());
-        path.push(cur_node.to_string());
-
-        let mut connections = al[cur_node].keys().collect::<Vec<_>>();
-        connections.sort_unstable();
-
-        for node in connections {
-            if !visited.contains(node) {
-                dfs_inner(al, visited, path, node);
+    Dfs::new(gr, &start).collect()
+}
+
+/// Same as [`dfs`], but also records visited nodes and edges of the DFS
+/// tree to `trace`.
+pub fn dfs_traced(gr: &Graph, start: String, trace: &mut Vec<Event>) -> Vec<String> {
+    let mut dfs = Dfs::new(gr, &start);
+    let mut path = Vec::new();
+    while let Some(node) = dfs.next() {
+        if let Some(from) = dfs.parent() {
+            trace.push(Event::Accepted {
+                from: from.to_string(),
+                to: node.to_string(),
+                weight: gr.adjacency_list[from][&node],
+            });
+        }
+        trace.push(Event::Settled {
+            node: node.to_string(),
+            distance: None,
+        });
+        path.push(node);
+    }
+
+    path
+}
+
+/// Kind of edge met by DFS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Edge to a node seen for the first time, it is a part of the DFS tree.
+    Tree,
+    /// Edge to a node which is still being visited, i.e. to an ancestor.
+    /// Such edge closes a cycle.
+    Back,
+    /// Edge to an already finished descendant. Only in oriented graphs.
+    Forward,
+    /// Edge to an already finished node in another branch or tree. Only in
+    /// oriented graphs.
+    Cross,
+}
+
+/// Receives events of depth-first search from [`dfs_visit`]. Every method
+/// does nothing by default.
+///
+/// Discover and finish times come from one counter, so node `v` is a
+/// descendant of `u` exactly when `u` is discovered before `v` and finished
+/// after it.
+pub trait DfsVisitor {
+    /// Node is seen for the first time.
+    fn discover(&mut self, _node: &str, _time: usize) {}
+
+    /// All nodes reachable from the node are visited.
+    fn finish(&mut self, _node: &str, _time: usize) {}
+
+    /// Edge is looked at. In not oriented graphs every edge is reported
+    /// once, as either a tree or a back edge.
+    fn edge(&mut self, _from: &str, _to: &str, _kind: EdgeKind) {}
+}
+
+/// Visitor remembering every event of DFS.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DfsRecord {
+    pub discovered: HashMap<String, usize>,
+    pub finished: HashMap<String, usize>,
+    /// Edges in order they were looked at.
+    pub edges: Vec<(String, String, EdgeKind)>,
+}
+
+impl DfsVisitor for DfsRecord {
+    fn discover(&mut self, node: &str, time: usize) {
+        self.discovered.insert(node.to_string(), time);
+    }
+
+    fn finish(&mut self, node: &str, time: usize) {
+        self.finished.insert(node.to_string(), time);
+    }
+
+    fn edge(&mut self, from: &str, to: &str, kind: EdgeKind) {
+        self.edges.push((from.to_string(), to.to_string(), kind));
+    }
+}
+
+/// Run DFS reporting its events to `visitor`. Starts from `start` if it is
+/// given and from every node in alphabetical order otherwise, so that the
+/// whole graph is visited. Times start from 1.
+pub fn dfs_visit(gr: &Graph, start: Option<&str>, visitor: &mut impl DfsVisitor) {
+    let roots = match start {
+        Some(start) => match gr.adjacency_list.get_key_value(start) {
+            Some((start, _)) => vec![start],
+            None => vec![],
+        },
+        None => {
+            let mut roots = gr.adjacency_list.keys().collect::<Vec<_>>();
+            roots.sort_unstable();
+            roots
+        }
+    };
+
+    let mut discovered = HashMap::<&String, usize>::new();
+    let mut finished = HashSet::<&String>::new();
+    let mut time = 0;
+    for root in roots {
+        if discovered.contains_key(root) {
+            continue;
+        }
+        time += 1;
+        discovered.insert(root, time);
+        visitor.discover(root, time);
+
+        // Nodes being visited with their neighbours, position of the next
+        // neighbour to look at and the node they were reached from
+        let mut stack = vec![(root, sorted_neighbours(gr, root), 0, None)];
+        while let Some((node, connections, next, parent)) = stack.last_mut() {
+            let (node, parent) = (*node, *parent);
+            let Some(&to) = connections.get(*next) else {
+                stack.pop();
+                time += 1;
+                finished.insert(node);
+                visitor.finish(node, time);
+                continue;
+            };
+            *next += 1;
+
+            let kind = match discovered.get(to) {
+                None => EdgeKind::Tree,
+                Some(_) if !gr.is_oriented() => {
+                    // Edge back to the parent is the tree edge itself, and
+                    // edge to a finished node was reported from that node
+                    if Some(to) == parent || finished.contains(to) {
+                        continue;
+                    }
+                    EdgeKind::Back
+                }
+                Some(_) if !finished.contains(to) => EdgeKind::Back,
+                Some(&time_to) if discovered[node] < time_to => EdgeKind::Forward,
+                Some(_) => EdgeKind::Cross,
+            };
+            visitor.edge(node, to, kind);
+
+            if kind == EdgeKind::Tree {
+                time += 1;
+                discovered.insert(to, time);
+                visitor.discover(to, time);
+                stack.push((to, sorted_neighbours(gr, to), 0, Some(node)));
             }
         }
+    }
+}
+
+/// Result of breadth-first search from a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BfsResult {
+    /// Reachable nodes in order of visiting.
+    pub order: Vec<String>,
+    /// Number of edges on the shortest path to every reachable node.
+    pub levels: HashMap<String, usize>,
+    /// Previous node on the shortest path to every reachable node except
+    /// the start.
+    pub parents: HashMap<String, String>,
+    /// Tree of shortest paths with every reachable node and edges from
+    /// parents to their children.
+    pub tree: Graph,
+}
+
+impl BfsResult {
+    /// Get shortest in terms of number of edges path from the start to
+    /// `end`, or empty vector if `end` is unreachable.
+    pub fn path_to(&self, end: &str) -> Vec<String> {
+        if !self.levels.contains_key(end) {
+            return Vec::new();
+        }
 
-        path.to_owned()
+        let mut path = vec![end.to_string()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.to_string());
+        }
+        path.reverse();
+        path
     }
+}
 
-    let mut visited = HashSet::<String>::new();
-    let mut path = Vec::<String>::new();
-    dfs_inner(&gr.get_adjacency_list(), &mut visited, &mut path, &start)
+/// Visit nodes reachable from `start` nearest first, checking neighbours of
+/// every node in alphabetical order.
+pub fn breadth_first(gr: &Graph, start: String) -> BfsResult {
+    breadth_first_traced(gr, start, &mut Vec::new())
 }
 
-/// Get vector of nodes in BFS (Breadth-First Search) order.
-pub fn bfs(gr: &Graph, start: String) -> Vec<String> {
-    fn bfs_inner(
-        al: &HashMap<String, HashMap<String, Option<EdgeWeight>>>,
-        visited: &mut HashSet<String>,
-        path: &mut Vec<String>,
-        cur_node: &String,
-    ) -> Vec<String> {
-        visited.insert(cur_node.to_string());
-
-        let mut connections = al[cur_node].keys().collect::<Vec<_>>();
-        connections.sort_unstable();
-
-        for node in connections {
-            if !visited.contains(node) {
-                bfs_inner(al, visited, path, node);
+fn breadth_first_traced(gr: &Graph, start: String, trace: &mut Vec<Event>) -> BfsResult {
+    let mut order = Vec::new();
+    let mut levels = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut tree = Graph::new(gr.is_weighted(), gr.is_oriented());
+
+    let mut bfs = Bfs::new(gr, &start);
+    while let Some(node) = bfs.next() {
+        tree.push_node(node.to_string()).unwrap();
+        match bfs.parent() {
+            Some(from) => {
+                let weight = gr.adjacency_list[from][&node];
+                trace.push(Event::Accepted {
+                    from: from.to_string(),
+                    to: node.to_string(),
+                    weight,
+                });
+                tree.push_edge(from.to_string(), node.to_string(), weight)
+                    .unwrap();
+                levels.insert(node.to_string(), levels[from] + 1);
+                parents.insert(node.to_string(), from.to_string());
+            }
+            None => {
+                levels.insert(node.to_string(), 0);
             }
         }
+        trace.push(Event::Settled {
+            node: node.to_string(),
+            distance: None,
+        });
+        order.push(node);
+    }
 
-        path.push(cur_node.to_string());
-        path.to_owned()
+    BfsResult {
+        order,
+        levels,
+        parents,
+        tree,
     }
+}
 
-    let mut visited = HashSet::<String>::new();
-    let mut path = Vec::<String>::new();
-    bfs_inner(&gr.get_adjacency_list(), &mut visited, &mut path, &start)
+/// Get graph of all shortest in terms of number of edges paths from `start`:
+/// oriented graph of nodes reachable from `start` with every edge which
+/// goes one level further from it. Paths from `start` in this graph are
+/// exactly the shortest paths in `gr`.
+pub fn shortest_paths_dag(gr: &Graph, start: String) -> Graph {
+    let levels = breadth_first(gr, start).levels;
+    let mut dag = Graph::new(gr.is_weighted(), true);
+    for node in levels.keys() {
+        dag.push_node(node.to_string()).unwrap();
+    }
+    for (from, to, weight) in gr.get_edges() {
+        if levels.contains_key(&from) && levels.get(&to) == Some(&(levels[&from] + 1)) {
+            dag.push_edge(from, to, weight).unwrap();
+        }
+    }
+
+    dag
 }
 
+/// Get vector of nodes in BFS (Breadth-First Search) order.
+pub fn bfs(gr: &Graph, start: String) -> Vec<String> {
+    breadth_first(gr, start).order
+}
+
+/// Same as [`bfs`], but also records visited nodes and edges of the BFS
+/// tree to `trace`.
+pub fn bfs_traced(gr: &Graph, start: String, trace: &mut Vec<Event>) -> Vec<String> {
+    breadth_first_traced(gr, start, trace).order
+}
+
+/// Get shortest in terms of number of edges path from `start` to `end`, or
+/// empty vector if there is none. Previous node on the shortest path to every
+/// node reachable from `start` is written to `parent`.
 pub fn bfs_to(
     gr: &Graph,
     start: String,
     end: String,
     parent: &mut HashMap<String, String>,
 ) -> Vec<String> {
-    fn bfs_inner(
-        al: &HashMap<String, HashMap<String, Option<EdgeWeight>>>,
-        visited: &mut HashSet<String>,
-        path: &mut Vec<String>,
-        cur_node: &String,
-        end: &String,
-        parent: &mut HashMap<String, String>,
-    ) -> Vec<String> {
-        visited.insert(cur_node.to_string());
-
-        if cur_node == end {
-            path.push(cur_node.to_string());
-            return path.to_owned();
-        }
-
-        let mut connections = al[cur_node].keys().collect::<Vec<_>>();
-        connections.sort_unstable();
-
-        for node in connections {
-            if !visited.contains(node) {
-                parent.insert(node.to_string(), cur_node.to_string());
-                let r = bfs_inner(al, visited, path, node, end, parent);
-                if !r.is_empty() {
-                    return  r;
-                }
+    let result = breadth_first(gr, start);
+    let path = result.path_to(&end);
+    parent.extend(result.parents);
+    path
+}
+
+/// Get vector of nodes in topological order, so that every edge goes from
+/// an earlier node to a later one. Fails if oriented graph has a cycle.
+pub fn topological_sort(gr: &Graph) -> GraphResult<Vec<String>> {
+    if !gr.is_oriented() {
+        return Err(GraphError {
+            msg: "Graph has to be oriented".to_string(),
+        });
+    }
+
+    let al = gr.get_adjacency_list();
+    let mut in_degree = HashMap::<String, usize>::new();
+    for node in gr.get_nodes() {
+        in_degree.entry(node).or_insert(0);
+    }
+    for (_, to, _) in gr.get_edges() {
+        *in_degree.get_mut(&to).unwrap() += 1;
+    }
+
+    let mut ready = in_degree
+        .iter()
+        .filter(|(_, d)| **d == 0)
+        .map(|(n, _)| n.to_string())
+        .collect::<BTreeSet<_>>();
+    let mut order = Vec::new();
+    while let Some(node) = ready.pop_first() {
+        for to in al[&node].keys() {
+            let d = in_degree.get_mut(to).unwrap();
+            *d -= 1;
+            if *d == 0 {
+                ready.insert(to.to_string());
             }
         }
+        order.push(node);
+    }
 
-        path.push(cur_node.to_string());
-        Vec::new()
+    if order.len() != in_degree.len() {
+        return Err(GraphError {
+            msg: "Graph has a cycle".to_string(),
+        });
     }
 
-    let mut visited = HashSet::<String>::new();
-    let mut path = Vec::<String>::new();
-    bfs_inner(
-        &gr.get_adjacency_list(),
-        &mut visited,
-        &mut path,
-        &start,
-        &end,
-        parent,
-    )
+    Ok(order)
 }
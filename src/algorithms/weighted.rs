@@ -1,4 +1,4 @@
-use crate::tasks::task2::solve22;
+use crate::algorithms::trace::{Event, Iteration};
 use crate::*;
 
 use std::cmp::Ordering;
@@ -7,7 +7,7 @@ use std::collections::{BinaryHeap, VecDeque};
 #[derive(Clone, Eq, PartialEq)]
 struct Weighted {
     node: String,
-    cost: u32,
+    cost: EdgeWeight,
 }
 
 impl Ord for Weighted {
@@ -25,51 +25,182 @@ impl PartialOrd for Weighted {
     }
 }
 
-pub fn dijkstra(
+/// Distance to every node and its predecessor on the shortest path.
+pub type DijkstraResult = (
+    HashMap<String, Option<EdgeWeight>>,
+    HashMap<String, Option<String>>,
+);
+
+/// Weight of the shortest path to every node and the path itself.
+pub type ShortestPaths = HashMap<String, (Option<EdgeWeight>, Vec<String>)>;
+
+/// Weight of the shortest path between every pair of nodes.
+pub type DistanceMatrix = HashMap<String, HashMap<String, Option<EdgeWeight>>>;
+
+/// Reason why Dijkstra algorithm cannot find shortest paths.
+#[derive(Debug, Clone)]
+pub enum DijkstraError {
+    /// Graph is not weighted or there is no start node.
+    Graph(GraphError),
+    /// Dijkstra algorithm gives wrong answers if any edge is negative.
+    NegativeEdge {
+        from: String,
+        to: String,
+        weight: EdgeWeight,
+    },
+    /// Weight of path to `to` through `from` does not fit into
+    /// [`EdgeWeight`].
+    Overflow { from: String, to: String },
+}
+
+impl fmt::Display for DijkstraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DijkstraError::Graph(e) => write!(f, "{e}"),
+            DijkstraError::NegativeEdge { from, to, weight } => write!(
+                f,
+                "Edge from {from:?} to {to:?} has negative weight {weight}, \
+                 Dijkstra algorithm works only with non-negative ones"
+            ),
+            DijkstraError::Overflow { from, to } => {
+                write!(f, "Weight of path to {to:?} through {from:?} is too big")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DijkstraError {}
+
+impl From<GraphError> for DijkstraError {
+    fn from(e: GraphError) -> Self {
+        DijkstraError::Graph(e)
+    }
+}
+
+impl From<DijkstraError> for GraphError {
+    fn from(e: DijkstraError) -> Self {
+        match e {
+            DijkstraError::Graph(e) => e,
+            e => GraphError { msg: e.to_string() },
+        }
+    }
+}
+
+pub fn dijkstra(gr: &Graph, start: String) -> Result<DijkstraResult, DijkstraError> {
+    dijkstra_traced(gr, start, &mut Vec::new())
+}
+
+/// Same as [`dijkstra`], but also records settled nodes and relaxed edges to
+/// `trace`.
+pub fn dijkstra_traced(
     gr: &Graph,
     start: String,
-) -> GraphResult<(
-    HashMap<String, Option<u32>>,
-    HashMap<String, Option<String>>,
-)> {
+    trace: &mut Vec<Event>,
+) -> Result<DijkstraResult, DijkstraError> {
+    dijkstra_inner(gr, &[start], None, trace)
+}
+
+/// Find shortest path from `start` to `end` and its weight, stopping as soon
+/// as it is found. Returns `None` if `end` is unreachable.
+pub fn dijkstra_to(
+    gr: &Graph,
+    start: String,
+    end: String,
+) -> Result<Option<(EdgeWeight, Vec<String>)>, DijkstraError> {
+    if !gr.adjacency_list.contains_key(&end) {
+        return Err(GraphError {
+            msg: format!("Node {end:?} does not exist"),
+        }
+        .into());
+    }
+
+    let (dist, prev) = dijkstra_inner(gr, &[start], Some(&end), &mut Vec::new())?;
+    Ok(dist[&end].map(|d| (d, path_to(&prev, &end))))
+}
+
+/// Find distance from the nearest of `starts` to every node. Previous node
+/// of every start is `None`, so paths lead to the nearest start.
+pub fn dijkstra_multi(gr: &Graph, starts: &[String]) -> Result<DijkstraResult, DijkstraError> {
+    dijkstra_inner(gr, starts, None, &mut Vec::new())
+}
+
+fn dijkstra_inner(
+    gr: &Graph,
+    starts: &[String],
+    end: Option<&String>,
+    trace: &mut Vec<Event>,
+) -> Result<DijkstraResult, DijkstraError> {
     if !gr.is_weighted() {
         return Err(GraphError {
             msg: "Graph has to be weighted".to_string(),
+        }
+        .into());
+    }
+    if let Some(start) = starts.iter().find(|s| !gr.adjacency_list.contains_key(*s)) {
+        return Err(GraphError {
+            msg: format!("Node {start:?} does not exist"),
+        }
+        .into());
+    }
+    let mut edges = gr.get_edges();
+    edges.sort();
+    if let Some((from, to, weight)) = edges.into_iter().find(|e| e.2.unwrap() < 0) {
+        return Err(DijkstraError::NegativeEdge {
+            from,
+            to,
+            weight: weight.unwrap(),
         });
     }
 
-    let al = gr.get_adjacency_list();
-    let nodes = gr.get_nodes();
+    let al = &gr.adjacency_list;
     let mut dist = HashMap::new();
     let mut prev = HashMap::new();
-
-    dist.insert(start.clone(), Some(0));
+    for node in gr.get_nodes() {
+        prev.insert(node.clone(), None);
+        dist.insert(node, None);
+    }
 
     let mut front = BinaryHeap::new();
-    front.push(Weighted {
-        node: start.clone(),
-        cost: 0,
-    });
-
-    for node in nodes {
-        prev.insert(node.clone(), None);
-        if node != start {
-            dist.insert(node.clone(), None);
-        }
+    for start in starts {
+        dist.insert(start.clone(), Some(0));
+        front.push(Weighted {
+            node: start.clone(),
+            cost: 0,
+        });
     }
-    *dist.get_mut(&start).unwrap() = Some(0u32);
 
-    while !front.is_empty() {
-        let u = front.pop().unwrap().node;
+    let mut settled = HashSet::new();
+    while let Some(Weighted { node: u, cost }) = front.pop() {
+        // Node can be in the queue several times, only the first is needed
+        if !settled.insert(u.clone()) {
+            continue;
+        }
+        trace.push(Event::Settled {
+            node: u.clone(),
+            distance: Some(cost),
+        });
+        if Some(&u) == end {
+            break;
+        }
 
         let mut connections = al[&u].keys().collect::<Vec<_>>();
         connections.sort_unstable();
 
         for neighbor in connections {
-            let alt = dist[&u].unwrap() + al[&u][neighbor].unwrap() as u32;
-            if dist[neighbor].is_none() || alt < dist[neighbor].unwrap() {
+            let alt = cost.checked_add(al[&u][neighbor].unwrap()).ok_or_else(|| {
+                DijkstraError::Overflow {
+                    from: u.clone(),
+                    to: neighbor.clone(),
+                }
+            })?;
+            if dist[neighbor].is_none_or(|d| alt < d) {
                 *dist.get_mut(neighbor).unwrap() = Some(alt);
                 *prev.get_mut(neighbor).unwrap() = Some(u.clone());
+                trace.push(Event::Relaxed {
+                    from: u.clone(),
+                    to: neighbor.clone(),
+                    distance: alt,
+                });
                 front.push(Weighted {
                     node: neighbor.clone(),
                     cost: alt,
@@ -81,26 +212,39 @@ pub fn dijkstra(
     Ok((dist, prev))
 }
 
-pub fn dijkstra_convenient(
-    gr: &Graph,
-    start: String,
-) -> GraphResult<HashMap<String, (Option<u32>, Vec<String>)>> {
-    let mut dijkstra_result = HashMap::new();
-    let nodes = gr.get_nodes();
-    let (dist, prev) = algorithms::weighted::dijkstra(gr, start)?;
-    for node in nodes {
-        let mut path = VecDeque::new();
-        let mut n = Some(node.clone());
-        while n.is_some() {
-            path.push_front(n.clone().unwrap());
-            n = prev[&n.unwrap()].clone();
-        }
-        dijkstra_result.insert(node.clone(), (dist[&node], Vec::from(path)));
+/// Follow previous nodes back from `node` to get path to it.
+fn path_to(prev: &HashMap<String, Option<String>>, node: &str) -> Vec<String> {
+    let mut path = VecDeque::new();
+    let mut n = Some(node.to_string());
+    while let Some(node) = n {
+        n = prev[&node].clone();
+        path.push_front(node);
     }
-    Ok(dijkstra_result)
+    Vec::from(path)
 }
 
-pub fn floyd(gr: &Graph) -> GraphResult<HashMap<String, HashMap<String, Option<EdgeWeight>>>> {
+pub fn dijkstra_convenient(gr: &Graph, start: String) -> GraphResult<ShortestPaths> {
+    let (dist, prev) = dijkstra(gr, start)?;
+    Ok(gr
+        .get_nodes()
+        .into_iter()
+        .map(|node| {
+            let path = path_to(&prev, &node);
+            (node.clone(), (dist[&node], path))
+        })
+        .collect())
+}
+
+pub fn floyd(gr: &Graph) -> GraphResult<DistanceMatrix> {
+    floyd_traced(gr, &mut Vec::new())
+}
+
+/// Same as [`floyd`], but also records the matrix before the first
+/// iteration and after every iteration to `iterations`.
+pub fn floyd_traced(
+    gr: &Graph,
+    iterations: &mut Vec<(String, DistanceMatrix)>,
+) -> GraphResult<DistanceMatrix> {
     if !gr.is_weighted() {
         return Err(GraphError {
             msg: "Graph has to be weighted".to_string(),
@@ -120,6 +264,7 @@ pub fn floyd(gr: &Graph) -> GraphResult<HashMap<String, HashMap<String, Option<E
     for (from, to, weight) in edges {
         am.get_mut(&from).unwrap().insert(to, weight);
     }
+    iterations.push(("initial".to_string(), am.clone()));
 
     for k in &nodes {
         for i in &nodes {
@@ -137,85 +282,350 @@ pub fn floyd(gr: &Graph) -> GraphResult<HashMap<String, HashMap<String, Option<E
                 }
             }
         }
+        iterations.push((format!("k = {k}"), am.clone()));
     }
 
     Ok(am)
 }
 
-// Bellman Ford indeed
+/// Shortest paths from a node found by Bellman–Ford algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BellmanFord {
+    /// Distance to every node and its predecessor on the shortest path.
+    Paths(DijkstraResult),
+    /// Cycle of negative weight reachable from the start, so some paths can
+    /// be made as light as wanted. Nodes are listed along the edges starting
+    /// from the least one, the last node leads back to the first.
+    NegativeCycle(Vec<String>),
+}
+
+/// Find shortest paths from `start` using Bellman–Ford algorithm. Unlike
+/// Dijkstra algorithm it works with negative edges too, unless there is
+/// a negative cycle reachable from `start`.
+pub fn bellman_ford(gr: &Graph, start: String) -> GraphResult<BellmanFord> {
+    bellman_ford_traced(gr, start, &mut Vec::new())
+}
+
+/// Same as [`bellman_ford`], but also records distances before the first
+/// round of relaxations and after every round that changed them to
+/// `iterations`, until some distance goes out of [`EdgeWeight`] range.
+pub fn bellman_ford_traced(
+    gr: &Graph,
+    start: String,
+    iterations: &mut Vec<Iteration>,
+) -> GraphResult<BellmanFord> {
+    if !gr.adjacency_list.contains_key(&start) {
+        return Err(GraphError {
+            msg: format!("Node {start:?} does not exist"),
+        });
+    }
+    bellman_ford_inner(gr, Some(&start), iterations)
+}
+
+/// Find a negative cycle anywhere in the graph, or return empty vector if
+/// there is none. Works as Bellman–Ford algorithm from a virtual node
+/// connected to every node by an edge of zero weight, so cycles not
+/// reachable from some particular node are found too.
+pub fn find_any_negative_cycle(gr: &Graph) -> GraphResult<Vec<String>> {
+    match bellman_ford_inner(gr, None, &mut Vec::new())? {
+        BellmanFord::Paths(_) => Ok(Vec::new()),
+        BellmanFord::NegativeCycle(cycle) => Ok(cycle),
+    }
+}
+
+/// Find a negative cycle reachable from `u`, or return empty vector if there
+/// is none.
 pub fn find_negative_cycle(gr: &Graph, u: String) -> GraphResult<Vec<String>> {
+    find_negative_cycle_traced(gr, u, &mut Vec::new())
+}
+
+/// Same as [`find_negative_cycle`], but also records distances before the
+/// first round of relaxations and after every round to `iterations`.
+pub fn find_negative_cycle_traced(
+    gr: &Graph,
+    u: String,
+    iterations: &mut Vec<Iteration>,
+) -> GraphResult<Vec<String>> {
+    match bellman_ford_traced(gr, u, iterations)? {
+        BellmanFord::Paths(_) => Ok(Vec::new()),
+        BellmanFord::NegativeCycle(cycle) => Ok(cycle),
+    }
+}
+
+/// Bellman–Ford algorithm from `start`, or from every node at once if it is
+/// `None`, which is the same as starting from a virtual super-source.
+fn bellman_ford_inner(
+    gr: &Graph,
+    start: Option<&String>,
+    iterations: &mut Vec<Iteration>,
+) -> GraphResult<BellmanFord> {
     if !gr.is_weighted() {
         return Err(GraphError {
             msg: "Graph has to be weighted".to_string(),
         });
     }
 
-    let mut path_weights = HashMap::new();
-    let mut parent = HashMap::new();
-    parent.insert(u.to_string(), u.to_string());
+    // Distances are kept wider than edge weights, as going around a negative
+    // cycle can make them smaller than any weight before it is found
     let nodes = gr.get_nodes();
+    let mut dist = HashMap::<String, Option<i64>>::new();
+    let mut prev = HashMap::new();
     for node in &nodes {
-        path_weights.insert(node.to_string(), None);
+        let d = start.is_none_or(|s| s == node).then_some(0);
+        dist.insert(node.to_string(), d);
+        prev.insert(node.to_string(), None);
     }
-    path_weights.insert(u, Some(0));
+    let mut edges = gr.get_edges();
+    edges.sort();
 
-    let edges = gr.get_edges();
+    // Rounds are recorded only while all distances fit into weights
+    let mut record = |label: String, dist: &HashMap<String, Option<i64>>| {
+        if let Ok(distances) = narrow(dist) {
+            iterations.push(Iteration { label, distances });
+            true
+        } else {
+            false
+        }
+    };
+    let mut recording = record("initial".to_string(), &dist);
+    // Shortest paths have at most n - 1 edges, so if anything changes in
+    // the n-th round, there is a negative cycle
     let n = nodes.len();
-    for _ in 1..n - 1 {
+    for round in 1..=n {
+        let mut relaxed = None;
         for (from, to, weight) in &edges {
-            if path_weights[to].is_none() || weight < &path_weights[to] {
-                path_weights.insert(to.to_string(), *weight);
-                parent.insert(to.to_string(), from.to_string());
+            let Some(d) = dist[from] else {
+                continue;
+            };
+            let alt = d
+                .checked_add(weight.unwrap() as i64)
+                .ok_or_else(|| GraphError {
+                    msg: format!("Weight of path to {to:?} through {from:?} is too big"),
+                })?;
+            if dist[to].is_none_or(|d| alt < d) {
+                dist.insert(to.to_string(), Some(alt));
+                prev.insert(to.to_string(), Some(from.to_string()));
+                relaxed = Some(to);
             }
         }
+
+        match relaxed {
+            None => break,
+            Some(node) if round == n => return Ok(BellmanFord::NegativeCycle(cycle(&prev, node))),
+            Some(_) if recording => recording = record(format!("round {round}"), &dist),
+            Some(_) => (),
+        }
     }
-    for (from, to, weight) in &edges {
-        if path_weights[from].is_none()
-            || weight.is_some()
-                && path_weights[to].unwrap() > path_weights[from].unwrap() + weight.unwrap()
-        {
-            let mut negative_cycle = Vec::new();
-            negative_cycle.push(from.to_string());
-            if !parent.contains_key(from) {
-                continue;
-            }
-            let mut p = &parent[from];
-            while p != from {
-                negative_cycle.push(p.to_string());
-                p = &parent[p];
-            }
-            return Ok(negative_cycle);
+
+    Ok(BellmanFord::Paths((narrow(&dist)?, prev)))
+}
+
+/// Convert distances back to [`EdgeWeight`], failing if any does not fit.
+fn narrow(dist: &HashMap<String, Option<i64>>) -> GraphResult<HashMap<String, Option<EdgeWeight>>> {
+    dist.iter()
+        .map(|(node, d)| {
+            let d = d
+                .map(EdgeWeight::try_from)
+                .transpose()
+                .map_err(|_| GraphError {
+                    msg: format!("Weight of path to {node:?} is too big"),
+                })?;
+            Ok((node.to_string(), d))
+        })
+        .collect()
+}
+
+/// Get cycle through previous nodes of `node`, which was relaxed in the last
+/// round of Bellman–Ford algorithm.
+fn cycle(prev: &HashMap<String, Option<String>>, node: &str) -> Vec<String> {
+    // Going back n times from such node surely ends up on the cycle
+    let mut on_cycle = node.to_string();
+    for _ in 0..prev.len() {
+        match &prev[&on_cycle] {
+            Some(p) => on_cycle = p.to_string(),
+            None => break,
         }
     }
 
-    Ok(Vec::new())
+    let mut cycle = vec![on_cycle.clone()];
+    let mut p = prev[&on_cycle].clone();
+    while let Some(node) = p.filter(|p| *p != on_cycle) {
+        p = prev[&node].clone();
+        cycle.push(node);
+    }
+    cycle.reverse();
+    let least = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap();
+    cycle.rotate_left(least);
+    cycle
+}
+
+/// Reason why Johnson algorithm cannot find shortest paths.
+#[derive(Debug, Clone)]
+pub enum JohnsonError {
+    /// Graph is not weighted or weights are too big.
+    Graph(GraphError),
+    /// Paths through this cycle of negative weight can be made as light as
+    /// wanted, so there are no shortest ones.
+    NegativeCycle(Vec<String>),
 }
 
+impl fmt::Display for JohnsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JohnsonError::Graph(e) => write!(f, "{e}"),
+            JohnsonError::NegativeCycle(cycle) => write!(
+                f,
+                "There are no shortest paths because of negative cycle: {}",
+                cycle.join(" -> ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for JohnsonError {}
+
+impl From<GraphError> for JohnsonError {
+    fn from(e: GraphError) -> Self {
+        JohnsonError::Graph(e)
+    }
+}
+
+impl From<DijkstraError> for JohnsonError {
+    fn from(e: DijkstraError) -> Self {
+        JohnsonError::Graph(e.into())
+    }
+}
+
+impl From<JohnsonError> for GraphError {
+    fn from(e: JohnsonError) -> Self {
+        match e {
+            JohnsonError::Graph(e) => e,
+            e => GraphError { msg: e.to_string() },
+        }
+    }
+}
+
+/// Find shortest paths between every pair of nodes using Johnson algorithm.
+/// Edges are reweighted with distances from a virtual node found by
+/// Bellman–Ford algorithm so that none of them is negative, and then
+/// Dijkstra algorithm is run from every node.
+pub fn johnson(gr: &Graph) -> Result<HashMap<String, ShortestPaths>, JohnsonError> {
+    let potential = match bellman_ford_inner(gr, None, &mut Vec::new())? {
+        BellmanFord::Paths((dist, _)) => dist,
+        BellmanFord::NegativeCycle(cycle) => return Err(JohnsonError::NegativeCycle(cycle)),
+    };
+    // Every node is reachable from the virtual one
+    let h = |node: &String| potential[node].unwrap() as i64;
+    let too_big = |from: &String, to: &String| GraphError {
+        msg: format!("Weight of path from {from:?} to {to:?} is too big"),
+    };
+
+    // Weight of any path from u to v changes by the same h(u) - h(v), so
+    // shortest paths stay the same
+    let mut reweighted = Graph::new(true, true);
+    for node in gr.get_nodes() {
+        reweighted.push_node(node)?;
+    }
+    for (from, to, weight) in gr.get_edges() {
+        let weight = weight.unwrap() as i64 + h(&from) - h(&to);
+        let weight = EdgeWeight::try_from(weight).map_err(|_| too_big(&from, &to))?;
+        reweighted.push_edge(from, to, Some(weight))?;
+    }
+
+    let mut res = HashMap::new();
+    for start in gr.get_nodes() {
+        let (dist, prev) = dijkstra(&reweighted, start.clone())?;
+        let mut paths = HashMap::new();
+        for (node, d) in dist {
+            let d = d
+                .map(|d| {
+                    let d = d as i64 - h(&start) + h(&node);
+                    EdgeWeight::try_from(d).map_err(|_| too_big(&start, &node))
+                })
+                .transpose()?;
+            let path = path_to(&prev, &node);
+            paths.insert(node, (d, path));
+        }
+        res.insert(start, paths);
+    }
+
+    Ok(res)
+}
+
+/// Find value of maximum flow from `source` to `sink` using Edmonds–Karp
+/// algorithm: flow is pushed along shortest paths of the residual graph,
+/// where flow already sent along an edge can be sent back. Edges of not
+/// oriented graph carry flow either way.
 pub fn edmonds_karp(gr: &Graph, source: String, sink: String) -> GraphResult<i32> {
     if !gr.is_weighted() {
         return Err(GraphError {
             msg: "Graph has to be weighted".to_string(),
         });
     }
+    if let Some(node) = [&source, &sink]
+        .into_iter()
+        .find(|n| !gr.adjacency_list.contains_key(*n))
+    {
+        return Err(GraphError {
+            msg: format!("Node {node:?} does not exist"),
+        });
+    }
+    if source == sink {
+        return Err(GraphError {
+            msg: "Source and sink have to be different".to_string(),
+        });
+    }
 
-    let mut alg_gr = gr.clone();
-    let mut max_flow = 0;
-    while let Some(path) = solve22(&alg_gr, source.clone()).get(&sink) {
-        if path.is_empty() {
-            break;
+    let mut residual = HashMap::<&String, HashMap<&String, i64>>::new();
+    for (u, adj) in &gr.adjacency_list {
+        for (v, weight) in adj {
+            let capacity = weight.unwrap();
+            if capacity < 0 {
+                return Err(GraphError {
+                    msg: format!("Edge from {u} to {v} has negative capacity {capacity}"),
+                });
+            }
+            *residual.entry(u).or_default().entry(v).or_default() += i64::from(capacity);
+            residual.entry(v).or_default().entry(u).or_default();
         }
-        let al = alg_gr.get_adjacency_list();
-        let mut path_flow = i32::MAX;
-        let mut weakest = ("".to_string(), "".to_string());
-        for i in 1..path.len() {
-            if al[&path[i - 1]][&path[i]].unwrap() < path_flow {
-                path_flow = al[&path[i - 1]][&path[i]].unwrap();
-                weakest = (path[i - 1].clone(), path[i].clone());
+    }
+
+    let mut max_flow = 0i64;
+    loop {
+        let mut prev = HashMap::from([(&source, &source)]);
+        let mut queue = VecDeque::from([&source]);
+        while let Some(u) = queue.pop_front() {
+            if u == &sink {
+                break;
             }
+            let mut next = residual[u]
+                .iter()
+                .filter(|(v, c)| **c > 0 && !prev.contains_key(*v))
+                .map(|(v, _)| *v)
+                .collect::<Vec<_>>();
+            next.sort();
+            for v in next {
+                prev.insert(v, u);
+                queue.push_back(v);
+            }
+        }
+        if !prev.contains_key(&sink) {
+            break;
+        }
+
+        let mut path = vec![&sink];
+        while *path.last().unwrap() != &source {
+            path.push(prev[path.last().unwrap()]);
+        }
+        let path_flow = path.windows(2).map(|e| residual[e[1]][e[0]]).min().unwrap();
+        for e in path.windows(2) {
+            *residual.get_mut(e[1]).unwrap().get_mut(e[0]).unwrap() -= path_flow;
+            *residual.get_mut(e[0]).unwrap().get_mut(e[1]).unwrap() += path_flow;
         }
-        alg_gr.pop_edge(weakest.0, weakest.1)?;
         max_flow += path_flow;
     }
 
-    Ok(max_flow)
+    EdgeWeight::try_from(max_flow).map_err(|_| GraphError {
+        msg: format!("Maximum flow {max_flow} does not fit into edge weight"),
+    })
 }
@@ -0,0 +1,272 @@
+use crate::algorithms::trace::Event;
+use crate::layout::Layout;
+use crate::*;
+
+use std::collections::BTreeMap;
+
+/// Algorithms which can be watched step by step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Dijkstra,
+    Prim,
+    Kruskal,
+    Dfs,
+    Bfs,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 5] = [
+        Algorithm::Dijkstra,
+        Algorithm::Prim,
+        Algorithm::Kruskal,
+        Algorithm::Dfs,
+        Algorithm::Bfs,
+    ];
+
+    /// Short name used in the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Dijkstra => "dijkstra",
+            Algorithm::Prim => "prim",
+            Algorithm::Kruskal => "kruskal",
+            Algorithm::Dfs => "dfs",
+            Algorithm::Bfs => "bfs",
+        }
+    }
+
+    /// Find algorithm by its short name.
+    pub fn from_name(name: &str) -> GraphResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|a| a.name() == name)
+            .ok_or_else(|| GraphError {
+                msg: format!("Unknown algorithm {name:?}"),
+            })
+    }
+
+    /// Check if algorithm starts from a given node.
+    pub fn needs_start(&self) -> bool {
+        !matches!(self, Algorithm::Prim | Algorithm::Kruskal)
+    }
+
+    /// Run algorithm on graph and get all its steps.
+    pub fn trace(&self, gr: &Graph, start: Option<&str>) -> GraphResult<Vec<Event>> {
+        let start = match start {
+            Some(start) if gr.get_adjacency_list().contains_key(start) => start.to_string(),
+            Some(start) => {
+                return Err(GraphError {
+                    msg: format!("Node {start:?} does not exist"),
+                })
+            }
+            None if self.needs_start() => {
+                return Err(GraphError {
+                    msg: format!("Algorithm {:?} needs a start node", self.name()),
+                })
+            }
+            None => String::new(),
+        };
+
+        let mut trace = Vec::new();
+        match self {
+            Algorithm::Dijkstra => {
+                algorithms::weighted::dijkstra_traced(gr, start, &mut trace)?;
+            }
+            Algorithm::Prim => {
+                algorithms::mst::prim_traced(gr, &mut trace)?;
+            }
+            Algorithm::Kruskal => {
+                algorithms::mst::kruskal_traced(gr, &mut trace)?;
+            }
+            Algorithm::Dfs => {
+                algorithms::traversals::dfs_traced(gr, start, &mut trace);
+            }
+            Algorithm::Bfs => {
+                algorithms::traversals::bfs_traced(gr, start, &mut trace);
+            }
+        }
+
+        Ok(trace)
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// State of the algorithm after some of its steps.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct State {
+    /// Best known distance and previous node on the path, for Dijkstra.
+    pub distances: BTreeMap<String, (Option<EdgeWeight>, Option<String>)>,
+    /// Settled nodes in order of settling.
+    pub settled: Vec<String>,
+    /// Edges of the tree built so far.
+    pub tree: Vec<Edge>,
+}
+
+/// Trace of an algorithm together with a position in it.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    graph: Graph,
+    events: Vec<Event>,
+    /// Number of events already happened.
+    step: usize,
+}
+
+impl Animation {
+    pub fn new(graph: Graph, events: Vec<Event>) -> Self {
+        Self {
+            graph,
+            events,
+            step: 0,
+        }
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Go one step forward. Returns false if animation is over.
+    pub fn forward(&mut self) -> bool {
+        if self.step < self.events.len() {
+            self.step += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Go one step back. Returns false if animation is at the beginning.
+    pub fn back(&mut self) -> bool {
+        if self.step > 0 {
+            self.step -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Jump to certain step, stopping at the end if there are fewer steps.
+    pub fn seek(&mut self, step: usize) {
+        self.step = step.min(self.events.len());
+    }
+
+    /// Get the last event that happened.
+    pub fn current(&self) -> Option<&Event> {
+        self.step.checked_sub(1).map(|i| &self.events[i])
+    }
+
+    /// Get state of the algorithm after current step.
+    pub fn state(&self) -> State {
+        let mut state = State::default();
+        let tracks_distances = self.events.iter().any(|e| {
+            matches!(
+                e,
+                Event::Relaxed { .. }
+                    | Event::Settled {
+                        distance: Some(_),
+                        ..
+                    }
+            )
+        });
+        for event in &self.events[..self.step] {
+            match event.clone() {
+                Event::Settled { node, distance } => {
+                    if distance.is_some() {
+                        state.distances.entry(node.clone()).or_default().0 = distance;
+                    }
+                    state.settled.push(node);
+                }
+                Event::Relaxed { from, to, distance } => {
+                    state.distances.insert(to, (Some(distance), Some(from)));
+                }
+                Event::Accepted { from, to, weight } => state.tree.push((from, to, weight)),
+                Event::Rejected { .. } => (),
+            }
+        }
+
+        if tracks_distances {
+            // Shortest paths tree consists of edges to previous nodes
+            state.tree = state
+                .distances
+                .iter()
+                .filter_map(|(to, (_, from))| {
+                    let from = from.as_ref()?;
+                    let weight = self.graph.get_adjacency_list()[from][to];
+                    Some((from.to_string(), to.to_string(), weight))
+                })
+                .collect();
+            for node in self.graph.get_nodes() {
+                state.distances.entry(node).or_default();
+            }
+        }
+
+        state
+    }
+
+    /// Draw graph with the tree built so far and a table describing current
+    /// state of the algorithm.
+    pub fn render(&self, color: bool) -> String {
+        let state = self.state();
+        let mut highlight = state
+            .tree
+            .iter()
+            .map(|(u, v, _)| (u.to_string(), v.to_string()))
+            .collect::<HashSet<_>>();
+        if let Some(Event::Relaxed { from, to, .. } | Event::Accepted { from, to, .. }) =
+            self.current()
+        {
+            highlight.insert((from.to_string(), to.to_string()));
+        }
+
+        let mut out = format!("Step {} of {}", self.step, self.events.len());
+        if let Some(event) = self.current() {
+            out.push_str(&format!(": {event}"));
+        }
+        out.push_str("\n\n");
+        out.push_str(
+            &self
+                .graph
+                .to_ascii(&Layout::auto(&self.graph), &highlight, color),
+        );
+        out.push_str("\n\n");
+
+        if !state.distances.is_empty() {
+            out.push_str(&format!(
+                "{:<12} {:>8}  {:<12} Settled\n",
+                "Node", "Distance", "Previous"
+            ));
+            for (node, (distance, prev)) in &state.distances {
+                out.push_str(&format!(
+                    "{:<12} {:>8}  {:<12} {}\n",
+                    node,
+                    distance.map_or("∞".to_string(), |d| d.to_string()),
+                    prev.as_deref().unwrap_or("-"),
+                    if state.settled.contains(node) {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                ));
+            }
+        } else {
+            out.push_str(&format!("Settled: {}\n", state.settled.join(", ")));
+            if self.graph.is_weighted() {
+                let weight = state.tree.iter().filter_map(|e| e.2).sum::<EdgeWeight>();
+                out.push_str(&format!("Tree weight: {weight}\n"));
+            }
+        }
+
+        out.trim_end().to_string()
+    }
+}
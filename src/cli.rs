@@ -0,0 +1,522 @@
+use graphs_at_ssu::animation::{Algorithm, Animation};
+use graphs_at_ssu::formats::ascii::path_edges;
+use graphs_at_ssu::formats::json::Json;
+use graphs_at_ssu::formats::svg::Overlay;
+use graphs_at_ssu::formats::table;
+use graphs_at_ssu::grader::GradedTask;
+use graphs_at_ssu::layout::Layout;
+use graphs_at_ssu::quiz::{QuestionKind, Random};
+use graphs_at_ssu::runner::{Task, TaskOutput};
+use graphs_at_ssu::script::Interpreter;
+use graphs_at_ssu::*;
+use inquire::InquireError;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::{self, IsTerminal, Read};
+
+/// Everything went fine.
+pub const EXIT_OK: i32 = 0;
+/// Graph could not be loaded or algorithm failed on it.
+pub const EXIT_GRAPH_ERROR: i32 = 1;
+/// Command line arguments are malformed.
+pub const EXIT_USAGE: i32 = 2;
+/// Graded answer is incorrect.
+pub const EXIT_INCORRECT: i32 = 3;
+
+const USAGE: &str = "\
+Usage: graphs-at-ssu [COMMAND [OPTIONS] FILE]
+
+Without a command the interactive mode is started.
+
+Commands:
+  dijkstra --from NODE FILE          Shortest paths from NODE to others
+  mst [--algo kruskal|prim] FILE     Minimum spanning tree (default: kruskal)
+  scc FILE                           Strongly connected components
+  maxflow --source NODE --sink NODE FILE
+                                     Maximum flow using Edmonds–Karp
+  run TASK FILE [NODES...]           Run any task, see `tasks` command of
+                                     the script mode for the list
+  convert [--format gr|json|tikz|mermaid|svg] INPUT [OUTPUT]
+                                     Rewrite graph (or Cargo.lock) in another
+                                     format (default: *.gr), printing it if
+                                     OUTPUT is omitted or `-`
+  draw FILE                          Draw graph in the terminal
+  table ALGORITHM [--from NODE] [--format markdown|latex] FILE
+                                     Print state of dijkstra, floyd or
+                                     negative-cycle after every iteration
+                                     as tables (default: markdown)
+  animate ALGORITHM [--from NODE] [--steps] FILE
+                                     Watch dijkstra, prim, kruskal, dfs or bfs
+                                     step by step; --steps prints all steps
+                                     at once instead
+  quiz [--kind mst|path|scc] [--nodes N] [--seed S]
+                                     Practice on exercises about random graphs
+  grade TASK FILE ANSWER [NODES...]  Check answer to task22 (from NODE), mst,
+                                     scc or maxflow (source and sink NODES)
+                                     read from ANSWER (`-` for stdin)
+  validate FILE                      Check that graph file is correct
+  script [--graph FILE] [--keep-going] SCRIPT
+                                     Run commands from SCRIPT (`-` for stdin)
+                                     on an empty or loaded graph, stopping
+                                     at the first failed line unless
+                                     --keep-going is given
+  repl [FILE]                        Type commands of the script mode one
+                                     by one, with completion and history
+  help                               Show this message
+
+Commands printing results of algorithms accept --json flag to print them
+as a single JSON object.
+
+Pictures (draw, convert to tikz, mermaid or svg) accept --path A,B,... and
+--mst kruskal|prim to emphasize edges of a path or of a minimum spanning
+tree; all but mermaid also accept --layout auto|layered|circular|force.
+Pictures in svg can have nodes colored by strongly connected components
+with --scc.
+
+Exit codes: 0 on success, 1 if graph cannot be loaded or processed,
+2 on invalid usage, 3 if graded answer is incorrect.";
+
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Graph(GraphError),
+    /// Command failed and has already told user why.
+    Reported,
+    /// Graded answer is wrong, the reason is already printed.
+    Incorrect,
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) => write!(f, "{msg}"),
+            CliError::Graph(e) => write!(f, "{e}"),
+            CliError::Reported => write!(f, "Command failed"),
+            CliError::Incorrect => write!(f, "Answer is incorrect"),
+        }
+    }
+}
+
+impl From<GraphError> for CliError {
+    fn from(e: GraphError) -> Self {
+        CliError::Graph(e)
+    }
+}
+
+type CliResult<T> = Result<T, CliError>;
+
+/// Options which do not take a value.
+const FLAGS: [&str; 4] = ["keep-going", "json", "scc", "steps"];
+
+/// Parsed command line: a command, its `--key value` options, `--flag`
+/// flags and positional arguments.
+struct Args {
+    command: String,
+    options: HashMap<String, String>,
+    flags: HashSet<String>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: Vec<String>) -> CliResult<Self> {
+        let mut args = args.into_iter();
+        let command = args.next().unwrap_or_default();
+        let mut options = HashMap::new();
+        let mut flags = HashSet::new();
+        let mut positional = Vec::new();
+
+        while let Some(arg) = args.next() {
+            if let Some(flag) = arg.strip_prefix("--").filter(|f| FLAGS.contains(f)) {
+                flags.insert(flag.to_string());
+            } else if let Some(key) = arg.strip_prefix("--") {
+                let value = args
+                    .next()
+                    .ok_or_else(|| CliError::Usage(format!("Option --{key} requires a value")))?;
+                options.insert(key.to_string(), value);
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        Ok(Args {
+            command,
+            options,
+            flags,
+            positional,
+        })
+    }
+
+    /// Get value of a required option.
+    fn option(&self, key: &str) -> CliResult<String> {
+        self.options
+            .get(key)
+            .cloned()
+            .ok_or_else(|| CliError::Usage(format!("Missing required option --{key}")))
+    }
+
+    /// Fail if some options or flags were given that command does not know
+    /// about.
+    fn allow_options(&self, known: &[&str]) -> CliResult<()> {
+        let mut given = self.options.keys().chain(self.flags.iter());
+        match given.find(|k| !known.contains(&k.as_str())) {
+            Some(k) => Err(CliError::Usage(format!(
+                "Unknown option --{k} for {:?}",
+                self.command
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Get layout chosen with `--layout` option.
+    fn layout(&self, gr: &Graph) -> CliResult<Layout> {
+        let name = self.options.get("layout").map_or("auto", |l| l.as_str());
+        if !["auto", "layered", "circular", "force"].contains(&name) {
+            return Err(CliError::Usage(format!(
+                "Unknown layout {name:?}, expected \"auto\", \"layered\", \"circular\" or \"force\""
+            )));
+        }
+        Ok(Layout::from_name(name, gr)?)
+    }
+
+    /// Get edges to highlight: consecutive nodes of `--path A,B,C` or
+    /// minimum spanning tree found by `--mst kruskal|prim`.
+    fn highlight(&self, gr: &Graph) -> CliResult<HashSet<(String, String)>> {
+        let mut edges = HashSet::new();
+        if let Some(path) = self.options.get("path") {
+            let path = path.split(',').map(|n| n.to_string()).collect::<Vec<_>>();
+            edges.extend(path_edges(&path));
+        }
+        let mst = match self.options.get("mst").map(|a| a.as_str()) {
+            None => vec![],
+            Some("kruskal") => algorithms::mst::kruskal(gr)?,
+            Some("prim") => algorithms::mst::prim(gr)?,
+            Some(algo) => {
+                return Err(CliError::Usage(format!(
+                    "Unknown MST algorithm {algo:?}, expected \"kruskal\" or \"prim\""
+                )))
+            }
+        };
+        edges.extend(mst.into_iter().map(|(u, v, _)| (u, v)));
+
+        Ok(edges)
+    }
+
+    /// Get positional arguments, checking their count.
+    fn positional(&self, min: usize, max: usize) -> CliResult<&[String]> {
+        if self.positional.len() < min || self.positional.len() > max {
+            return Err(CliError::Usage(format!(
+                "{:?} expects {} file argument{}",
+                self.command,
+                if min == max {
+                    min.to_string()
+                } else {
+                    format!("{min} to {max}")
+                },
+                if max == 1 { "" } else { "s" }
+            )));
+        }
+        Ok(&self.positional)
+    }
+
+    /// Print result of a task as JSON if `--json` was given and as text
+    /// otherwise.
+    fn print(&self, output: &TaskOutput) {
+        if self.flags.contains("json") {
+            println!("{}", output.to_json());
+        } else {
+            println!("{output}");
+        }
+    }
+
+    /// Load graph from the only positional argument.
+    fn graph(&self) -> CliResult<Graph> {
+        let path = &self.positional(1, 1)?[0];
+        Ok(Graph::load(path.to_string())?)
+    }
+}
+
+/// Run non-interactive command and return process exit code.
+pub fn run(args: Vec<String>) -> i32 {
+    let res = Args::parse(args).and_then(|args| execute(&args));
+    match res {
+        Ok(()) => EXIT_OK,
+        Err(CliError::Usage(msg)) => {
+            safe_err!("{msg}");
+            eprintln!("\n{USAGE}");
+            EXIT_USAGE
+        }
+        Err(CliError::Graph(e)) => {
+            safe_err!("{e}");
+            EXIT_GRAPH_ERROR
+        }
+        Err(CliError::Reported) => EXIT_GRAPH_ERROR,
+        Err(CliError::Incorrect) => EXIT_INCORRECT,
+    }
+}
+
+fn execute(args: &Args) -> CliResult<()> {
+    match args.command.as_str() {
+        "dijkstra" => {
+            args.allow_options(&["from", "json"])?;
+            let start = args.option("from")?;
+            args.print(&Task::Dijkstra.run(&args.graph()?, &[start])?);
+        }
+        "mst" => {
+            args.allow_options(&["algo", "json"])?;
+            let task = match args.options.get("algo").map(|a| a.as_str()) {
+                None | Some("kruskal") => Task::Kruskal,
+                Some("prim") => Task::Prim,
+                Some(algo) => {
+                    return Err(CliError::Usage(format!(
+                        "Unknown MST algorithm {algo:?}, expected \"kruskal\" or \"prim\""
+                    )))
+                }
+            };
+            match task.run(&args.graph()?, &[])? {
+                TaskOutput::Tree(mst) if !args.flags.contains("json") => {
+                    for (from, to, weight) in &mst {
+                        println!("{from} - {to}: {weight}");
+                    }
+                    println!(
+                        "Total weight: {}",
+                        mst.iter().map(|e| e.2).sum::<EdgeWeight>()
+                    );
+                }
+                output => args.print(&output),
+            }
+        }
+        "scc" => {
+            args.allow_options(&["json"])?;
+            args.print(&Task::StronglyConnected.run(&args.graph()?, &[])?);
+        }
+        "maxflow" => {
+            args.allow_options(&["source", "sink", "json"])?;
+            let nodes = [args.option("source")?, args.option("sink")?];
+            args.print(&Task::MaxFlow.run(&args.graph()?, &nodes)?);
+        }
+        "run" => {
+            args.allow_options(&["json"])?;
+            if args.positional.len() < 2 {
+                return Err(CliError::Usage(
+                    "\"run\" expects task name and file arguments".to_string(),
+                ));
+            }
+            let task =
+                Task::from_name(&args.positional[0]).map_err(|e| CliError::Usage(e.to_string()))?;
+            let gr = Graph::load(args.positional[1].clone())?;
+            args.print(&task.run(&gr, &args.positional[2..])?);
+        }
+        "convert" => {
+            args.allow_options(&["format", "layout", "path", "mst", "scc"])?;
+            let files = args.positional(1, 2)?;
+            let gr = Graph::load(files[0].clone())?;
+            let text = match args.options.get("format").map(|f| f.as_str()) {
+                None | Some("gr") => gr.pretty_view(),
+                Some("json") => gr.to_json().to_string(),
+                Some("tikz") => gr.to_tikz(&args.layout(&gr)?, &args.highlight(&gr)?),
+                Some("mermaid") => gr.to_mermaid(&args.highlight(&gr)?),
+                Some("svg") => {
+                    let mut overlay = Overlay {
+                        edges: args.highlight(&gr)?,
+                        ..Overlay::default()
+                    };
+                    if args.flags.contains("scc") {
+                        overlay.groups = Overlay::components(&tasks::task2::solve21(&gr)?).groups;
+                    }
+                    gr.to_svg(&args.layout(&gr)?, &overlay)
+                }
+                Some(format) => return Err(CliError::Usage(format!(
+                    "Unknown format {format:?}, expected \"gr\", \"json\", \"tikz\", \"mermaid\" or \"svg\""
+                ))),
+            };
+            match files.get(1).filter(|p| *p != "-") {
+                Some(out) => std::fs::write(out, text).map_err(GraphError::from)?,
+                None => println!("{text}"),
+            }
+        }
+        "draw" => {
+            args.allow_options(&["path", "mst", "layout"])?;
+            let gr = args.graph()?;
+            println!(
+                "{}",
+                gr.to_ascii(
+                    &args.layout(&gr)?,
+                    &args.highlight(&gr)?,
+                    io::stdout().is_terminal()
+                )
+            );
+        }
+        "animate" => {
+            args.allow_options(&["from", "steps"])?;
+            let positional = args.positional(2, 2)?;
+            let algorithm =
+                Algorithm::from_name(&positional[0]).map_err(|e| CliError::Usage(e.to_string()))?;
+            let gr = Graph::load(positional[1].clone())?;
+            let trace = algorithm.trace(&gr, args.options.get("from").map(|s| s.as_str()))?;
+            let mut animation = Animation::new(gr, trace);
+            if args.flags.contains("steps") {
+                let color = io::stdout().is_terminal();
+                println!("{}", animation.render(color));
+                while animation.forward() {
+                    println!("\n{}", animation.render(color));
+                }
+            } else {
+                crate::player::play(animation).map_err(|e| GraphError { msg: e.to_string() })?;
+            }
+        }
+        "table" => {
+            args.allow_options(&["from", "format"])?;
+            let positional = args.positional(2, 2)?;
+            let gr = Graph::load(positional[1].clone())?;
+            let start = || match args.options.get("from") {
+                Some(start) => Ok(start.to_string()),
+                None => gr.get_nodes().into_iter().next().ok_or_else(|| {
+                    CliError::Graph(GraphError {
+                        msg: "Graph is empty".to_string(),
+                    })
+                }),
+            };
+            let tables = match positional[0].as_str() {
+                "dijkstra" => vec![table::dijkstra_table(&gr, args.option("from")?)?],
+                "floyd" => table::floyd_tables(&gr)?,
+                "negative-cycle" => vec![table::negative_cycle_table(&gr, start()?)?],
+                algo => {
+                    return Err(CliError::Usage(format!(
+                        "Unknown algorithm {algo:?}, expected \"dijkstra\", \"floyd\" or \"negative-cycle\""
+                    )))
+                }
+            };
+            let tables = match args.options.get("format").map(|f| f.as_str()) {
+                None | Some("markdown") => {
+                    tables.iter().map(|t| t.to_markdown()).collect::<Vec<_>>()
+                }
+                Some("latex") => tables.iter().map(|t| t.to_latex()).collect(),
+                Some(format) => {
+                    return Err(CliError::Usage(format!(
+                        "Unknown table format {format:?}, expected \"markdown\" or \"latex\""
+                    )))
+                }
+            };
+            println!("{}", tables.join("\n\n"));
+        }
+        "quiz" => {
+            args.allow_options(&["kind", "nodes", "seed"])?;
+            args.positional(0, 0)?;
+            let kind = match args.options.get("kind") {
+                Some(kind) => Some(
+                    QuestionKind::from_name(kind).map_err(|e| CliError::Usage(e.to_string()))?,
+                ),
+                None => None,
+            };
+            let number = |key: &str| -> CliResult<Option<u64>> {
+                match args.options.get(key) {
+                    Some(n) => n.parse().map(Some).map_err(|_| {
+                        CliError::Usage(format!("Option --{key} expects a number, got {n:?}"))
+                    }),
+                    None => Ok(None),
+                }
+            };
+            let size = number("nodes")?.map(|n| n as usize);
+            let max = crate::exercise::MAX_SIZE;
+            if let Some(n) = size.filter(|n| !(2..=max).contains(n)) {
+                return Err(CliError::Usage(format!(
+                    "Option --nodes expects a number from 2 to {max}, got {n}"
+                )));
+            }
+            let mut rng = match number("seed")? {
+                Some(seed) => Random::new(seed),
+                None => Random::from_time(),
+            };
+            match crate::exercise::run(kind, size, &mut rng) {
+                Ok(())
+                | Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => (),
+                Err(e) => return Err(GraphError { msg: e.to_string() }.into()),
+            }
+        }
+        "grade" => {
+            args.allow_options(&["json"])?;
+            if args.positional.len() < 3 {
+                return Err(CliError::Usage(
+                    "\"grade\" expects task name, graph file and answer file arguments".to_string(),
+                ));
+            }
+            let task = GradedTask::from_name(&args.positional[0])
+                .map_err(|e| CliError::Usage(e.to_string()))?;
+            let gr = Graph::load(args.positional[1].clone())?;
+            let mut answer = String::new();
+            if args.positional[2] == "-" {
+                io::stdin()
+                    .read_to_string(&mut answer)
+                    .map_err(GraphError::from)?;
+            } else {
+                answer = std::fs::read_to_string(&args.positional[2]).map_err(GraphError::from)?;
+            }
+
+            let verdict = task.grade(&gr, &args.positional[3..], &answer)?;
+            if args.flags.contains("json") {
+                let json = Json::object([
+                    ("correct", Json::Bool(verdict.correct)),
+                    ("reason", Json::from(verdict.reason.as_str())),
+                ]);
+                println!("{json}");
+            } else if verdict.correct {
+                println!("Correct");
+            } else {
+                println!("Incorrect: {}", verdict.reason);
+            }
+            if !verdict.correct {
+                return Err(CliError::Incorrect);
+            }
+        }
+        "validate" => {
+            args.allow_options(&[])?;
+            let gr = args.graph()?;
+            println!(
+                "OK: {} nodes, {} edges",
+                gr.get_nodes().len(),
+                gr.get_edges().len()
+            );
+        }
+        "script" => {
+            args.allow_options(&["graph", "keep-going"])?;
+            let path = &args.positional(1, 1)?[0];
+            let mut source = String::new();
+            if path == "-" {
+                io::stdin()
+                    .read_to_string(&mut source)
+                    .map_err(GraphError::from)?;
+            } else {
+                source = std::fs::read_to_string(path).map_err(GraphError::from)?;
+            }
+            let gr = match args.options.get("graph") {
+                Some(file) => Graph::load(file.to_string())?,
+                None => Graph::new(false, false),
+            };
+
+            let mut interpreter = Interpreter::new(gr);
+            let errors = interpreter.run(
+                &source,
+                !args.flags.contains("keep-going"),
+                &mut io::stdout(),
+            );
+            for e in &errors {
+                safe_err!("{e}");
+            }
+            if !errors.is_empty() {
+                return Err(CliError::Reported);
+            }
+        }
+        "repl" => {
+            args.allow_options(&[])?;
+            let gr = match args.positional(0, 1)?.first() {
+                Some(file) => Graph::load(file.to_string())?,
+                None => Graph::new(false, false),
+            };
+            crate::repl::run(gr);
+        }
+        "help" | "--help" | "-h" => println!("{USAGE}"),
+        cmd => return Err(CliError::Usage(format!("Unknown command {cmd:?}"))),
+    }
+
+    Ok(())
+}
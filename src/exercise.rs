@@ -0,0 +1,66 @@
+use graphs_at_ssu::layout::Layout;
+use graphs_at_ssu::quiz::{Exercise, QuestionKind, Random};
+use graphs_at_ssu::*;
+use inquire::{error::InquireResult, Confirm, CustomType, Select, Text};
+use std::collections::HashSet;
+
+/// Largest graph quiz can generate, so that nodes have one-letter names.
+pub const MAX_SIZE: usize = 26;
+
+/// Ask questions about random graphs until user has had enough. Kind of
+/// questions and size of graphs are asked for if not given.
+pub fn run(kind: Option<QuestionKind>, size: Option<usize>, rng: &mut Random) -> InquireResult<()> {
+    loop {
+        let kind = match kind {
+            Some(kind) => kind,
+            None => Select::new("What to practice:", QuestionKind::ALL.to_vec()).prompt()?,
+        };
+        let size = match size {
+            Some(size) => size,
+            None => CustomType::<usize>::new("Number of nodes:")
+                .with_default((6, &|n| n.to_string()))
+                .with_error_message("Enter a positive integer")
+                .prompt()?
+                .clamp(2, MAX_SIZE),
+        };
+        let exercise = match Exercise::generate(kind, size, rng) {
+            Ok(exercise) => exercise,
+            Err(e) => {
+                safe_err!("Cannot generate exercise: {e}");
+                return Ok(());
+            }
+        };
+
+        let layout = Layout::auto(&exercise.graph);
+        println!(
+            "\n{}\n\n{}\n",
+            exercise.graph.to_ascii(&layout, &HashSet::new(), true),
+            exercise.graph.pretty_view()
+        );
+        let answer = Text::new(&exercise.question()).prompt()?;
+        match exercise.check(&answer) {
+            Ok(verdict) if verdict.correct => println!("\x1b[1;32mCorrect!\x1b[0m"),
+            Ok(verdict) => {
+                println!("\x1b[1;31mWrong\x1b[0m: {}", verdict.reason);
+                let solution = exercise.solution().and_then(|text| {
+                    let edges = exercise.solution_edges()?;
+                    Ok((text, edges))
+                });
+                match solution {
+                    Ok((text, edges)) => {
+                        println!("Correct answer: {text}");
+                        if !edges.is_empty() {
+                            println!("\n{}", exercise.graph.to_ascii(&layout, &edges, true));
+                        }
+                    }
+                    Err(e) => safe_err!("Cannot find the correct answer: {e}"),
+                }
+            }
+            Err(e) => safe_err!("Cannot check the answer: {e}"),
+        }
+
+        if !Confirm::new("One more?").with_default(true).prompt()? {
+            return Ok(());
+        }
+    }
+}
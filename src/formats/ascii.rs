@@ -0,0 +1,417 @@
+use crate::layout::Layout;
+use crate::*;
+
+use std::cmp::Ordering;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Empty,
+    /// Part of an edge, bits show in which directions line goes. Edge is
+    /// `None` if lines of several edges meet here.
+    Line {
+        bits: u8,
+        highlighted: bool,
+        edge: Option<usize>,
+    },
+    Text {
+        c: char,
+        highlighted: bool,
+    },
+}
+
+/// Edge being drawn.
+#[derive(Debug, Clone, Copy)]
+struct Stroke {
+    edge: usize,
+    highlighted: bool,
+}
+
+/// Character canvas that grows when something is drawn outside of it.
+struct Canvas {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    fn new(height: usize) -> Self {
+        Self {
+            cells: vec![Vec::new(); height],
+        }
+    }
+
+    fn cell(&mut self, col: usize, row: usize) -> &mut Cell {
+        let line = &mut self.cells[row];
+        if line.len() <= col {
+            line.resize(col + 1, Cell::Empty);
+        }
+        &mut line[col]
+    }
+
+    fn line(&mut self, col: usize, row: usize, bits: u8, stroke: Stroke) {
+        let cell = self.cell(col, row);
+        *cell = match *cell {
+            Cell::Line {
+                bits: old,
+                highlighted,
+                edge,
+            } => Cell::Line {
+                bits: old | bits,
+                highlighted: highlighted || stroke.highlighted,
+                edge: edge.filter(|e| *e == stroke.edge),
+            },
+            Cell::Empty => Cell::Line {
+                bits,
+                highlighted: stroke.highlighted,
+                edge: Some(stroke.edge),
+            },
+            text => text,
+        };
+    }
+
+    /// Check if cells from `col` on are lines of this edge only.
+    fn owns(&mut self, col: usize, row: usize, len: usize, edge: usize) -> bool {
+        (col..col + len)
+            .all(|c| matches!(self.cell(c, row), Cell::Line { edge: e, .. } if *e == Some(edge)))
+    }
+
+    /// Check if `text` can be put at the cell without covering anything or
+    /// touching text on the right.
+    fn fits(&mut self, col: usize, row: usize, text: &str) -> bool {
+        let len = text.chars().count();
+        (col..col + len).all(|c| matches!(self.cell(c, row), Cell::Empty))
+            && !matches!(self.cell(col + len, row), Cell::Text { .. })
+    }
+
+    fn horizontal(&mut self, row: usize, from: usize, to: usize, stroke: Stroke) {
+        let (a, b) = (from.min(to), from.max(to));
+        for col in a..=b {
+            let mut bits = 0;
+            if col > a {
+                bits |= LEFT;
+            }
+            if col < b {
+                bits |= RIGHT;
+            }
+            self.line(col, row, bits, stroke);
+        }
+    }
+
+    fn vertical(&mut self, col: usize, from: usize, to: usize, stroke: Stroke) {
+        let (a, b) = (from.min(to), from.max(to));
+        for row in a..=b {
+            let mut bits = 0;
+            if row > a {
+                bits |= UP;
+            }
+            if row < b {
+                bits |= DOWN;
+            }
+            self.line(col, row, bits, stroke);
+        }
+    }
+
+    fn text(&mut self, col: usize, row: usize, text: &str, highlighted: bool) {
+        for (i, c) in text.chars().enumerate() {
+            *self.cell(col + i, row) = Cell::Text { c, highlighted };
+        }
+    }
+
+    fn render(&self, color: bool) -> String {
+        let mut out = Vec::new();
+        for line in &self.cells {
+            let mut s = String::new();
+            for cell in line {
+                let (c, highlighted) = match *cell {
+                    Cell::Empty => (' ', false),
+                    Cell::Line {
+                        bits, highlighted, ..
+                    } => (glyph(bits, highlighted), highlighted),
+                    Cell::Text { c, highlighted } => (c, highlighted),
+                };
+                if color && highlighted {
+                    s.push_str(&format!("\x1b[1;33m{c}\x1b[0m"));
+                } else {
+                    s.push(c);
+                }
+            }
+            out.push(s.trim_end().to_string());
+        }
+
+        out.join("\n").trim_matches('\n').to_string()
+    }
+}
+
+/// Box-drawing character for line going in given directions.
+fn glyph(bits: u8, heavy: bool) -> char {
+    let (light, bold) = match bits {
+        b if b == UP | DOWN || b == UP || b == DOWN => ('│', '┃'),
+        b if b == LEFT | RIGHT || b == LEFT || b == RIGHT => ('─', '━'),
+        b if b == DOWN | RIGHT => ('┌', '┏'),
+        b if b == DOWN | LEFT => ('┐', '┓'),
+        b if b == UP | RIGHT => ('└', '┗'),
+        b if b == UP | LEFT => ('┘', '┛'),
+        b if b == UP | DOWN | RIGHT => ('├', '┣'),
+        b if b == UP | DOWN | LEFT => ('┤', '┫'),
+        b if b == DOWN | LEFT | RIGHT => ('┬', '┳'),
+        b if b == UP | LEFT | RIGHT => ('┴', '┻'),
+        b if b == UP | DOWN | LEFT | RIGHT => ('┼', '╋'),
+        _ => (' ', ' '),
+    };
+
+    if heavy {
+        bold
+    } else {
+        light
+    }
+}
+
+/// Place nodes on rows and columns of the canvas, keeping order given by
+/// layout. Returns column of the node center and number of its row of
+/// nodes, counting from the top.
+fn place(gr: &Graph, layout: &Layout) -> HashMap<String, (usize, usize)> {
+    let nodes = gr.get_nodes();
+    let width = |node: &String| node.chars().count() + 2;
+    let cell_width = nodes.iter().map(width).max().unwrap_or(0) + 3;
+
+    // Nodes with (almost) the same vertical position share a row. If the
+    // layout does not put nodes in rows, there would be too many of them,
+    // so nearby ones are put together.
+    let exact = |y: f64| (y * 1e6).round() as i64;
+    let mut ys = nodes
+        .iter()
+        .map(|n| exact(layout.positions[n].1))
+        .collect::<Vec<_>>();
+    ys.sort();
+    ys.dedup();
+    let max_rows = 2 * (nodes.len() as f64).sqrt().ceil() as usize;
+    let levels = if ys.len() > max_rows { max_rows } else { 0 };
+    let level = |y: f64| {
+        if levels > 0 {
+            (y * (levels - 1) as f64).round() as i64
+        } else {
+            exact(y)
+        }
+    };
+    if levels > 0 {
+        ys = (0..levels as i64).collect();
+    }
+    let mut rows = vec![Vec::<&String>::new(); ys.len()];
+    for node in &nodes {
+        let y = level(layout.positions[node].1);
+        rows[ys.binary_search(&y).unwrap()].push(node);
+    }
+    rows.retain(|r| !r.is_empty());
+
+    let widest = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let span = (cell_width * (widest.max(nodes.len() / 2) + 1)) as f64;
+
+    let mut placed = HashMap::new();
+    for (r, row) in rows.iter_mut().enumerate() {
+        row.sort_by(|a, b| layout.positions[*a].0.total_cmp(&layout.positions[*b].0));
+        let mut free_from = 0;
+        for node in row.iter() {
+            let half = width(node) / 2;
+            let wanted = (layout.positions[*node].0 * span).round() as usize;
+            let col = wanted.max(free_from + half);
+            free_from = col - half + width(node) + 2;
+            placed.insert(node.to_string(), (col, r));
+        }
+    }
+
+    placed
+}
+
+impl Graph {
+    /// Draw graph on a character canvas using box-drawing characters.
+    /// Edges from `highlight` are drawn with heavy lines (and in color if
+    /// `color` is set); for not oriented graphs direction of highlighted
+    /// edges does not matter.
+    pub fn to_ascii(
+        &self,
+        layout: &Layout,
+        highlight: &HashSet<(String, String)>,
+        color: bool,
+    ) -> String {
+        let placed = place(self, layout);
+        let levels = placed.values().map(|p| p.1).max().map_or(0, |l| l + 1);
+
+        let is_highlighted = |u: &String, v: &String| {
+            highlight.contains(&(u.to_string(), v.to_string()))
+                || !self.is_oriented && highlight.contains(&(v.to_string(), u.to_string()))
+        };
+
+        let mut edges = self.get_edges();
+        edges.sort();
+        edges.retain(|(u, v, _)| self.is_oriented || u <= v);
+
+        // Every edge going sideways gets a row for its horizontal part in the
+        // gap next to its target, where gap `l` is above nodes of row `l`.
+        // Edges share such a row only if their parts are apart.
+        let mut spans = Vec::new();
+        for (i, (u, v, _)) in edges.iter().enumerate() {
+            let ((uc, ul), (vc, vl)) = (placed[u], placed[v]);
+            let gap = match ul.cmp(&vl) {
+                _ if u == v => continue,
+                Ordering::Equal if uc < vc => ul + 1,
+                Ordering::Equal => ul,
+                Ordering::Less => vl,
+                Ordering::Greater => vl + 1,
+            };
+            if uc != vc {
+                spans.push((gap, uc.min(vc), uc.max(vc), i));
+            }
+        }
+        spans.sort();
+        let mut channels = vec![Vec::<usize>::new(); levels + 1];
+        let mut channel_of = HashMap::new();
+        for (gap, a, b, i) in spans {
+            let ends = &mut channels[gap];
+            let c = match ends.iter().position(|end| end + 1 < a) {
+                Some(c) => c,
+                None => {
+                    ends.push(0);
+                    ends.len() - 1
+                }
+            };
+            ends[c] = b;
+            channel_of.insert(i, (gap, c));
+        }
+
+        // Next to every row of nodes there is a row for arrows and edges
+        // leaving the nodes, and rows of horizontal parts are between them
+        let mut first_channel = vec![0; levels + 1];
+        let mut node_row = vec![0; levels];
+        let mut height = 0;
+        for gap in 0..=levels {
+            if gap > 0 {
+                height += 1;
+            }
+            first_channel[gap] = height;
+            height += channels[gap].len();
+            if gap < levels {
+                node_row[gap] = height + 1;
+                height += 2;
+            }
+        }
+        let mut canvas = Canvas::new(height);
+
+        let mut labels = Vec::new();
+        let mut loops = HashMap::new();
+        for (i, (u, v, weight)) in edges.iter().enumerate() {
+            let h = is_highlighted(u, v);
+            let weight = weight.map(|w| w.to_string());
+            if u == v {
+                loops.insert(u.to_string(), (weight, h));
+                continue;
+            }
+
+            let ((uc, ul), (vc, vl)) = (placed[u], placed[v]);
+            let (ur, vr) = (node_row[ul], node_row[vl]);
+            let stroke = Stroke {
+                edge: i,
+                highlighted: h,
+            };
+            // Edge going straight down or up has no horizontal part
+            let channel = match channel_of.get(&i) {
+                Some(&(gap, c)) => first_channel[gap] + c,
+                None if vr > ur => ur + 1,
+                None => ur - 1,
+            };
+            let start = if channel > ur { ur + 1 } else { ur - 1 };
+            let end = if channel > vr { vr + 1 } else { vr - 1 };
+            canvas.line(uc, start, if start > ur { UP } else { DOWN }, stroke);
+            canvas.vertical(uc, start, channel, stroke);
+            canvas.horizontal(channel, uc, vc, stroke);
+            canvas.vertical(vc, channel, end, stroke);
+            if self.is_oriented {
+                let arrow = if end < vr { '▼' } else { '▲' };
+                canvas.text(vc, end, &arrow.to_string(), h);
+            } else {
+                canvas.line(vc, end, if end < vr { DOWN } else { UP }, stroke);
+            }
+            if let Some(w) = weight {
+                labels.push((i, u, v, uc, vc, channel, w, h));
+            }
+        }
+
+        // Weights are put on the middle of horizontal parts of edges, or as
+        // close to it as there is room, covering nothing but their own edge.
+        // Weights which do not fit are listed under the drawing.
+        let mut legend = Vec::new();
+        for (i, u, v, uc, vc, row, w, h) in labels {
+            let len = w.chars().count();
+            let (a, b) = (uc.min(vc), uc.max(vc));
+            let middle = (a + b + 1).saturating_sub(len) / 2;
+            let mut cols = (a + 1..b.saturating_sub(len)).collect::<Vec<_>>();
+            cols.sort_by_key(|c| c.abs_diff(middle));
+            // Edge going straight down or up has no room on it, so the weight
+            // is put right next to it
+            let fits = |canvas: &mut Canvas, col: usize| {
+                if a == b {
+                    col == b + 1 && canvas.owns(b, row, 1, i) && canvas.fits(col, row, &w)
+                } else {
+                    canvas.owns(col, row, len, i)
+                }
+            };
+            if a == b {
+                cols.push(b + 1);
+            }
+            match cols.into_iter().find(|&col| fits(&mut canvas, col)) {
+                Some(col) => canvas.text(col, row, &w, h),
+                None => legend.push(format!("{u}{}{v}: {w}", self.edge_mark())),
+            }
+        }
+
+        let mut ends = Vec::new();
+        for (node, (col, row)) in &placed {
+            let h = highlight.iter().any(|(u, v)| u == node || v == node);
+            let label = format!("[{node}]");
+            let start = col - label.chars().count() / 2;
+            let row = node_row[*row];
+            canvas.text(start, row, &label, h);
+            ends.push((node, start + label.chars().count(), row));
+        }
+        // Loops are marked after all labels are drawn, so they cannot run
+        // into a label of the next node
+        ends.sort();
+        for (node, after, row) in ends {
+            if let Some((weight, h)) = loops.get(node) {
+                let mut mark = "↺".to_string();
+                if let Some(w) = weight {
+                    mark.push_str(w);
+                }
+                if canvas.fits(after, row, &mark) {
+                    canvas.text(after, row, &mark, *h);
+                } else if let Some(w) = weight {
+                    legend.push(format!("{node}{}{node}: {w}", self.edge_mark()));
+                }
+            }
+        }
+
+        let drawing = canvas.render(color);
+        if legend.is_empty() {
+            return drawing;
+        }
+        legend.sort();
+        format!("{drawing}\n\nWeights:\n{}", legend.join("\n"))
+    }
+
+    fn edge_mark(&self) -> &'static str {
+        if self.is_oriented {
+            "→"
+        } else {
+            "–"
+        }
+    }
+}
+
+/// Get edges between consecutive nodes of the path.
+pub fn path_edges(path: &[String]) -> HashSet<(String, String)> {
+    path.windows(2)
+        .map(|w| (w[0].to_string(), w[1].to_string()))
+        .collect()
+}
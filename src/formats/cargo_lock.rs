@@ -0,0 +1,195 @@
+use crate::*;
+
+#[derive(Debug, Default)]
+struct Package {
+    name: Option<String>,
+    version: Option<String>,
+    source: Option<String>,
+    dependencies: Vec<String>,
+}
+
+impl Graph {
+    /// Create new oriented graph of packages from given `Cargo.lock` file.
+    ///
+    /// Every `[[package]]` entry becomes a node named `"name version"` and
+    /// every entry of its `dependencies` array becomes an edge from the
+    /// package to the dependency. If packages of the same name and version
+    /// come from different sources, their nodes are named
+    /// `"name version (source)"`, the way `Cargo.lock` refers to them.
+    pub fn from_cargo_lock(path: String) -> GraphResult<Self> {
+        let mut contents = String::new();
+        File::open(path)?.read_to_string(&mut contents)?;
+
+        Self::from_cargo_lock_str(&contents)
+    }
+
+    /// Create new oriented graph of packages from contents of `Cargo.lock`.
+    pub fn from_cargo_lock_str(contents: &str) -> GraphResult<Self> {
+        let packages = parse_packages(contents)?;
+        let names = node_names(&packages);
+
+        let mut gr = Graph::new(false, true);
+        for name in &names {
+            gr.push_node(name.to_string())?;
+        }
+        for (p, name) in packages.iter().zip(&names) {
+            for dep in &p.dependencies {
+                let dep = resolve_dependency(&packages, dep).ok_or_else(|| GraphError {
+                    msg: format!("Package {name:?} depends on unknown package {dep:?}"),
+                })?;
+                gr.push_edge(name.to_string(), names[dep].to_string(), None)?;
+            }
+        }
+
+        Ok(gr)
+    }
+}
+
+/// Get node name of every package: `name version`, or `name version
+/// (source)` if there is another package with the same name and version.
+fn node_names(packages: &[Package]) -> Vec<String> {
+    let short = |p: &Package| {
+        format!(
+            "{} {}",
+            p.name.as_deref().unwrap_or_default(),
+            p.version.as_deref().unwrap_or_default()
+        )
+    };
+    let mut count = HashMap::<String, usize>::new();
+    for p in packages {
+        *count.entry(short(p)).or_default() += 1;
+    }
+
+    packages
+        .iter()
+        .map(|p| match &p.source {
+            Some(source) if count[&short(p)] > 1 => format!("{} ({source})", short(p)),
+            _ => short(p),
+        })
+        .collect()
+}
+
+/// Find index of package for dependency written as `name`, `name version`
+/// or `name version (source)`.
+fn resolve_dependency(packages: &[Package], dep: &str) -> Option<usize> {
+    let (dep, source) = match dep.split_once(" (") {
+        Some((dep, source)) => (dep, Some(source.strip_suffix(')')?)),
+        None => (dep, None),
+    };
+    let mut words = dep.split_whitespace();
+    let name = words.next()?;
+    let version = words.next();
+    if words.next().is_some() {
+        return None;
+    }
+
+    let mut found = packages.iter().enumerate().filter(|(_, p)| {
+        p.name.as_deref() == Some(name)
+            && version.is_none_or(|v| p.version.as_deref() == Some(v))
+            && source.is_none_or(|s| p.source.as_deref() == Some(s))
+    });
+    match (found.next(), found.next()) {
+        (Some((i, _)), None) => Some(i),
+        _ => None,
+    }
+}
+
+/// Parse all `[[package]]` tables, ignoring every other table and key.
+fn parse_packages(contents: &str) -> GraphResult<Vec<Package>> {
+    let mut packages = Vec::<Package>::new();
+    let mut in_package = false;
+
+    let mut lines = contents.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with('[') {
+            in_package = line == "[[package]]";
+            if in_package {
+                packages.push(Package::default());
+            }
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(|| GraphError {
+            msg: format!("Invalid syntax on line {}", i + 1),
+        })?;
+        let mut value = value.trim().to_string();
+        if value.starts_with('[') {
+            while !strip_comment(&value).trim_end().ends_with(']') {
+                let (_, next) = lines.next().ok_or_else(|| GraphError {
+                    msg: format!("Unterminated array on line {}", i + 1),
+                })?;
+                value.push_str(strip_comment(next));
+            }
+        }
+
+        let package = packages.last_mut().unwrap();
+        match key.trim() {
+            "name" => package.name = Some(parse_string(&value, i)?),
+            "version" => package.version = Some(parse_string(&value, i)?),
+            "source" => package.source = Some(parse_string(&value, i)?),
+            "dependencies" => package.dependencies = parse_string_array(&value, i)?,
+            _ => {}
+        }
+    }
+
+    for p in &packages {
+        if p.name.is_none() || p.version.is_none() {
+            return Err(GraphError {
+                msg: format!(
+                    "Package {:?} is missing its {}",
+                    p.name.as_deref().unwrap_or("?"),
+                    if p.name.is_none() { "name" } else { "version" }
+                ),
+            });
+        }
+    }
+
+    Ok(packages)
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_string(value: &str, line: usize) -> GraphResult<String> {
+    let value = strip_comment(value).trim();
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .map(|v| v.to_string())
+        .ok_or_else(|| GraphError {
+            msg: format!("Expected a string on line {}, found {value:?}", line + 1),
+        })
+}
+
+fn parse_string_array(value: &str, line: usize) -> GraphResult<Vec<String>> {
+    let value = strip_comment(value).trim();
+    let inner = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or_else(|| GraphError {
+            msg: format!("Expected an array on line {}, found {value:?}", line + 1),
+        })?;
+
+    inner
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| parse_string(s, line))
+        .collect()
+}
@@ -0,0 +1,237 @@
+use crate::runner::TaskOutput;
+use crate::*;
+
+use std::collections::BTreeMap;
+
+/// Minimal JSON value, enough to describe graphs and results of algorithms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    /// Object with keys kept in given order.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Create object from pairs of keys and values.
+    pub fn object<K: ToString>(pairs: impl IntoIterator<Item = (K, Json)>) -> Self {
+        Json::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    /// Create object with keys sorted alphabetically.
+    pub fn sorted_object<K: ToString, V>(map: &HashMap<K, V>, f: impl Fn(&V) -> Json) -> Self {
+        let sorted = map
+            .iter()
+            .map(|(k, v)| (k.to_string(), f(v)))
+            .collect::<BTreeMap<_, _>>();
+        Json::Object(sorted.into_iter().collect())
+    }
+
+    /// Create array of strings.
+    pub fn strings<S: ToString>(items: impl IntoIterator<Item = S>) -> Self {
+        Json::Array(
+            items
+                .into_iter()
+                .map(|s| Json::String(s.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::String(s.to_string())
+    }
+}
+
+impl From<EdgeWeight> for Json {
+    fn from(n: EdgeWeight) -> Self {
+        Json::Number(n as i64)
+    }
+}
+
+impl From<u32> for Json {
+    fn from(n: u32) -> Self {
+        Json::Number(n as i64)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(Json::Null, |v| v.into())
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Number(n) => write!(f, "{n}"),
+            Json::String(s) => write_escaped(f, s),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(pairs) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+fn edge_json(from: &str, to: &str, weight: Option<EdgeWeight>) -> Json {
+    Json::object([
+        ("from", Json::from(from)),
+        ("to", Json::from(to)),
+        ("weight", Json::from(weight)),
+    ])
+}
+
+fn path_json(path: &[String]) -> Json {
+    if path.is_empty() {
+        Json::Null
+    } else {
+        Json::strings(path)
+    }
+}
+
+impl Graph {
+    /// Get JSON object describing graph properties, nodes and edges.
+    pub fn to_json(&self) -> Json {
+        let mut edges = self.get_edges();
+        edges.sort();
+
+        Json::object([
+            ("weighted", Json::Bool(self.is_weighted())),
+            ("oriented", Json::Bool(self.is_oriented())),
+            ("nodes", Json::strings(self.get_nodes())),
+            (
+                "edges",
+                Json::Array(
+                    edges
+                        .iter()
+                        .map(|(from, to, weight)| edge_json(from, to, *weight))
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+}
+
+impl TaskOutput {
+    /// Get machine-readable representation of the result.
+    pub fn to_json(&self) -> Json {
+        match self {
+            TaskOutput::Nodes(nodes) => Json::object([("nodes", Json::strings(nodes))]),
+            TaskOutput::Order(nodes) => Json::object([("order", Json::strings(nodes))]),
+            TaskOutput::Graph(gr) => gr.to_json(),
+            TaskOutput::Components(components) => {
+                let mut components = components.iter().collect::<Vec<_>>();
+                components.sort();
+                Json::object([(
+                    "components",
+                    Json::Array(components.into_iter().map(Json::strings).collect()),
+                )])
+            }
+            TaskOutput::UnweightedPaths(paths) => {
+                Json::object([("paths", Json::sorted_object(paths, |p| path_json(p)))])
+            }
+            TaskOutput::Tree(edges) => Json::object([
+                (
+                    "edges",
+                    Json::Array(
+                        edges
+                            .iter()
+                            .map(|(from, to, weight)| edge_json(from, to, Some(*weight)))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "total_weight",
+                    Json::from(edges.iter().map(|e| e.2).sum::<EdgeWeight>()),
+                ),
+            ]),
+            TaskOutput::Paths(start, paths) => Json::object([
+                ("from", Json::from(start.as_str())),
+                (
+                    "distances",
+                    Json::sorted_object(paths, |(w, _)| Json::from(*w)),
+                ),
+                (
+                    "predecessors",
+                    Json::sorted_object(paths, |(w, path)| match w {
+                        Some(_) if path.len() > 1 => Json::from(path[path.len() - 2].as_str()),
+                        _ => Json::Null,
+                    }),
+                ),
+                (
+                    "paths",
+                    Json::sorted_object(paths, |(w, path)| match w {
+                        Some(_) => path_json(path),
+                        None => Json::Null,
+                    }),
+                ),
+            ]),
+            TaskOutput::AllPairs(res) => Json::object([(
+                "paths",
+                Json::sorted_object(res, |paths| {
+                    Json::sorted_object(paths, |(w, path)| {
+                        Json::object([
+                            ("weight", Json::from(*w)),
+                            (
+                                "path",
+                                match w {
+                                    Some(_) => path_json(path),
+                                    None => Json::Null,
+                                },
+                            ),
+                        ])
+                    })
+                }),
+            )]),
+            TaskOutput::Distances(start, weights) => Json::object([
+                ("from", Json::from(start.as_str())),
+                (
+                    "distances",
+                    Json::sorted_object(weights, |w| Json::from(*w)),
+                ),
+            ]),
+            TaskOutput::Cycle(cycle) => Json::object([("negative_cycle", path_json(cycle))]),
+            TaskOutput::Flow(flow) => Json::object([("max_flow", Json::from(*flow))]),
+        }
+    }
+}
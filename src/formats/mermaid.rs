@@ -0,0 +1,40 @@
+use crate::*;
+
+impl Graph {
+    /// Get Mermaid flowchart of the graph. Edges from `emphasis` are drawn
+    /// with thick lines; for not oriented graphs their direction does not
+    /// matter.
+    pub fn to_mermaid(&self, emphasis: &HashSet<(String, String)>) -> String {
+        let nodes = self.get_nodes();
+        let id = |node: &String| format!("n{}", nodes.binary_search(node).unwrap());
+
+        let mut out = String::from("flowchart LR\n");
+        for node in &nodes {
+            out.push_str(&format!(
+                "    {}[\"{}\"]\n",
+                id(node),
+                node.replace('"', "#quot;")
+            ));
+        }
+
+        let mut edges = self.get_edges();
+        edges.sort();
+        for (u, v, weight) in &edges {
+            if !self.is_oriented && u > v {
+                continue;
+            }
+            let emphasized = emphasis.contains(&(u.to_string(), v.to_string()))
+                || !self.is_oriented && emphasis.contains(&(v.to_string(), u.to_string()));
+            let link = match (self.is_oriented, emphasized) {
+                (true, false) => "-->",
+                (true, true) => "==>",
+                (false, false) => "---",
+                (false, true) => "===",
+            };
+            let label = weight.map_or(String::new(), |w| format!("|{w}|"));
+            out.push_str(&format!("    {} {link}{label} {}\n", id(u), id(v)));
+        }
+
+        out.trim_end().to_string()
+    }
+}
@@ -0,0 +1,21 @@
+use crate::*;
+
+pub mod ascii;
+pub mod cargo_lock;
+pub mod json;
+pub mod mermaid;
+pub mod svg;
+pub mod table;
+pub mod tikz;
+
+impl Graph {
+    /// Create new graph from given file, picking its format by extension:
+    /// `*.lock` files are read as `Cargo.lock`, everything else as `*.gr`.
+    pub fn load(path: String) -> GraphResult<Self> {
+        if path.ends_with(".lock") {
+            Self::from_cargo_lock(path)
+        } else {
+            Self::from_file(path)
+        }
+    }
+}
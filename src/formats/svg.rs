@@ -0,0 +1,196 @@
+use crate::layout::Layout;
+use crate::*;
+
+use std::collections::BTreeSet;
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 600.0;
+const MARGIN: f64 = 40.0;
+const RADIUS: f64 = 18.0;
+/// How far edges between the same nodes in opposite directions are bent.
+const BEND: f64 = 25.0;
+
+const EDGE_COLOR: &str = "#555555";
+const HIGHLIGHT_COLOR: &str = "#d62728";
+/// Fill colors of node groups, e.g. strongly connected components.
+const PALETTE: [&str; 10] = [
+    "#aec7e8", "#ffbb78", "#98df8a", "#ff9896", "#c5b0d5", "#c49c94", "#f7b6d2", "#dbdb8d",
+    "#9edae5", "#c7c7c7",
+];
+
+/// Results of algorithms drawn on top of the graph.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overlay {
+    /// Edges drawn thick and red; for not oriented graphs their direction
+    /// does not matter.
+    pub edges: HashSet<(String, String)>,
+    /// Index of the group each node belongs to, nodes of a group share color.
+    pub groups: HashMap<String, usize>,
+}
+
+impl Overlay {
+    /// Highlight edges between consecutive nodes of the path.
+    pub fn path(path: &[String]) -> Self {
+        Self {
+            edges: formats::ascii::path_edges(path),
+            ..Self::default()
+        }
+    }
+
+    /// Color nodes by components, e.g. strongly connected ones.
+    pub fn components(components: &HashSet<BTreeSet<String>>) -> Self {
+        // Sort components to always give them the same colors
+        let mut sorted = components.iter().collect::<Vec<_>>();
+        sorted.sort();
+        let groups = sorted
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, c)| c.iter().map(move |node| (node.to_string(), i)))
+            .collect();
+
+        Self {
+            groups,
+            ..Self::default()
+        }
+    }
+}
+
+/// Escape characters that cannot be used in XML text.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Graph {
+    /// Get SVG image of the graph with nodes placed according to layout.
+    pub fn to_svg(&self, layout: &Layout, overlay: &Overlay) -> String {
+        let point = |node: &String| {
+            let (x, y) = layout.positions[node];
+            (
+                MARGIN + x * (WIDTH - 2.0 * MARGIN),
+                MARGIN + y * (HEIGHT - 2.0 * MARGIN),
+            )
+        };
+
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+             viewBox=\"0 0 {WIDTH} {HEIGHT}\" font-family=\"sans-serif\" font-size=\"14\">\n"
+        );
+        out.push_str("  <defs>\n");
+        for (id, color) in [("arrow", EDGE_COLOR), ("arrow-highlight", HIGHLIGHT_COLOR)] {
+            out.push_str(&format!(
+                "    <marker id=\"{id}\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+                 markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">\
+                 <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"{color}\"/></marker>\n"
+            ));
+        }
+        out.push_str("  </defs>\n");
+
+        let al = self.get_adjacency_list();
+        let mut edges = self.get_edges();
+        edges.sort();
+        let mut labels = String::new();
+        for (u, v, weight) in &edges {
+            if !self.is_oriented && u > v {
+                continue;
+            }
+            let highlighted = overlay.edges.contains(&(u.to_string(), v.to_string()))
+                || !self.is_oriented && overlay.edges.contains(&(v.to_string(), u.to_string()));
+            let (color, width, marker) = if highlighted {
+                (HIGHLIGHT_COLOR, 3, "arrow-highlight")
+            } else {
+                (EDGE_COLOR, 1, "arrow")
+            };
+            let marker = if self.is_oriented {
+                format!(" marker-end=\"url(#{marker})\"")
+            } else {
+                String::new()
+            };
+
+            let (start, end) = (point(u), point(v));
+            let (d, label_at) = if u == v {
+                // Loop above the node
+                let (x, y) = start;
+                let d = format!(
+                    "M {:.1} {:.1} C {:.1} {:.1} {:.1} {:.1} {:.1} {:.1}",
+                    x - RADIUS * 0.6,
+                    y - RADIUS * 0.8,
+                    x - RADIUS * 1.5,
+                    y - RADIUS * 3.5,
+                    x + RADIUS * 1.5,
+                    y - RADIUS * 3.5,
+                    x + RADIUS * 0.6,
+                    y - RADIUS * 0.8
+                );
+                (d, (x, y - RADIUS * 2.8))
+            } else {
+                // Opposite edges are bent to different sides
+                let bend = if self.is_oriented && al[v].contains_key(u) {
+                    BEND
+                } else {
+                    0.0
+                };
+                let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                let len = (dx * dx + dy * dy).sqrt().max(1e-6);
+                let control = (
+                    (start.0 + end.0) / 2.0 - dy / len * bend,
+                    (start.1 + end.1) / 2.0 + dx / len * bend,
+                );
+                let from = towards(start, control, RADIUS);
+                let to = towards(end, control, RADIUS);
+                let d = format!(
+                    "M {:.1} {:.1} Q {:.1} {:.1} {:.1} {:.1}",
+                    from.0, from.1, control.0, control.1, to.0, to.1
+                );
+                let middle = (
+                    0.25 * from.0 + 0.5 * control.0 + 0.25 * to.0,
+                    0.25 * from.1 + 0.5 * control.1 + 0.25 * to.1,
+                );
+                (d, middle)
+            };
+            out.push_str(&format!(
+                "  <path d=\"{d}\" fill=\"none\" stroke=\"{color}\" stroke-width=\"{width}\"{marker}/>\n"
+            ));
+            if let Some(w) = weight {
+                labels.push_str(&format!(
+                    "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" dominant-baseline=\"middle\" \
+                     fill=\"{color}\" stroke=\"white\" stroke-width=\"4\" paint-order=\"stroke\">{w}</text>\n",
+                    label_at.0, label_at.1
+                ));
+            }
+        }
+        // Labels go after all edges so that no edge is drawn over them
+        out.push_str(&labels);
+
+        for node in self.get_nodes() {
+            let (x, y) = point(&node);
+            let fill = overlay
+                .groups
+                .get(&node)
+                .map_or("white", |g| PALETTE[g % PALETTE.len()]);
+            out.push_str(&format!(
+                "  <circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"{RADIUS}\" fill=\"{fill}\" stroke=\"black\"/>\n"
+            ));
+            out.push_str(&format!(
+                "  <text x=\"{x:.1}\" y=\"{y:.1}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+                xml_escape(&node)
+            ));
+        }
+        out.push_str("</svg>\n");
+
+        out
+    }
+}
+
+/// Move point `distance` units in direction of `target`.
+fn towards(point: (f64, f64), target: (f64, f64), distance: f64) -> (f64, f64) {
+    let (dx, dy) = (target.0 - point.0, target.1 - point.1);
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0.0 {
+        point
+    } else {
+        (point.0 + dx / len * distance, point.1 + dy / len * distance)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::algorithms::trace::{self, Iteration};
+use crate::algorithms::weighted::{self, DistanceMatrix};
+use crate::*;
+
+/// Table with optional title, ready to be written as Markdown or LaTeX.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub title: Option<String>,
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Write distance, using `∞` for unreachable nodes.
+fn distance(d: &Option<EdgeWeight>) -> String {
+    d.map_or("∞".to_string(), |d| d.to_string())
+}
+
+impl Table {
+    /// Table with a row per iteration and a column per node.
+    pub fn from_iterations(nodes: &[String], iterations: &[Iteration]) -> Self {
+        let mut header = vec!["Iteration".to_string()];
+        header.extend(nodes.iter().cloned());
+        let rows = iterations
+            .iter()
+            .map(|it| {
+                let mut row = vec![it.label.to_string()];
+                row.extend(nodes.iter().map(|n| distance(&it.distances[n])));
+                row
+            })
+            .collect();
+
+        Self {
+            title: None,
+            header,
+            rows,
+        }
+    }
+
+    /// Table of distances between every pair of nodes.
+    pub fn from_matrix(title: &str, nodes: &[String], matrix: &DistanceMatrix) -> Self {
+        let mut header = vec![String::new()];
+        header.extend(nodes.iter().cloned());
+        let rows = nodes
+            .iter()
+            .map(|from| {
+                let mut row = vec![from.to_string()];
+                row.extend(nodes.iter().map(|to| distance(&matrix[from][to])));
+                row
+            })
+            .collect();
+
+        Self {
+            title: Some(title.to_string()),
+            header,
+            rows,
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let line = |cells: &[String]| {
+            let cells = cells
+                .iter()
+                .map(|c| c.replace('|', "\\|"))
+                .collect::<Vec<_>>();
+            format!("| {} |", cells.join(" | "))
+        };
+
+        let mut out = String::new();
+        if let Some(title) = &self.title {
+            out.push_str(&format!("**{title}**\n\n"));
+        }
+        out.push_str(&line(&self.header));
+        out.push('\n');
+        out.push_str(&format!("|{}\n", "---|".repeat(self.header.len())));
+        for row in &self.rows {
+            out.push_str(&line(row));
+            out.push('\n');
+        }
+
+        out.trim_end().to_string()
+    }
+
+    pub fn to_latex(&self) -> String {
+        let line = |cells: &[String]| {
+            let cells = cells.iter().map(|c| latex_escape(c)).collect::<Vec<_>>();
+            format!("  {} \\\\", cells.join(" & "))
+        };
+
+        let mut out = String::new();
+        if let Some(title) = &self.title {
+            out.push_str(&format!("\\textbf{{{}}}\n\n", latex_escape(title)));
+        }
+        out.push_str(&format!(
+            "\\begin{{tabular}}{{l|{}}}\n",
+            "c".repeat(self.header.len().saturating_sub(1))
+        ));
+        out.push_str(&line(&self.header));
+        out.push_str("\n  \\hline\n");
+        for row in &self.rows {
+            out.push_str(&line(row));
+            out.push('\n');
+        }
+        out.push_str("\\end{tabular}");
+
+        out
+    }
+}
+
+/// Escape characters that have special meaning in LaTeX.
+pub fn latex_escape(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '\\' => out.push_str("\\textbackslash{}"),
+            '∞' => out.push_str("$\\infty$"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Distances from `start` after every node settled by Dijkstra algorithm.
+pub fn dijkstra_table(gr: &Graph, start: String) -> GraphResult<Table> {
+    let mut events = Vec::new();
+    weighted::dijkstra_traced(gr, start, &mut events)?;
+    let iterations = trace::dijkstra_iterations(gr, &events);
+    Ok(Table::from_iterations(&gr.get_nodes(), &iterations))
+}
+
+/// Distance matrix after every iteration of Floyd–Warshall algorithm.
+pub fn floyd_tables(gr: &Graph) -> GraphResult<Vec<Table>> {
+    let mut iterations = Vec::new();
+    weighted::floyd_traced(gr, &mut iterations)?;
+    let nodes = gr.get_nodes();
+    Ok(iterations
+        .iter()
+        .map(|(label, matrix)| Table::from_matrix(label, &nodes, matrix))
+        .collect())
+}
+
+/// Distances from `start` after every round of Bellman–Ford algorithm used
+/// for searching a negative cycle.
+pub fn negative_cycle_table(gr: &Graph, start: String) -> GraphResult<Table> {
+    let mut iterations = Vec::new();
+    weighted::find_negative_cycle_traced(gr, start, &mut iterations)?;
+    Ok(Table::from_iterations(&gr.get_nodes(), &iterations))
+}
@@ -0,0 +1,73 @@
+use crate::formats::table::latex_escape;
+use crate::layout::Layout;
+use crate::*;
+
+/// Size of the picture in centimeters.
+const WIDTH: f64 = 10.0;
+const HEIGHT: f64 = 7.0;
+
+impl Graph {
+    /// Get TikZ picture of the graph with nodes placed according to layout.
+    /// Edges from `emphasis` are drawn thick and red; for not oriented graphs
+    /// their direction does not matter.
+    pub fn to_tikz(&self, layout: &Layout, emphasis: &HashSet<(String, String)>) -> String {
+        let nodes = self.get_nodes();
+        let id = |node: &String| format!("n{}", nodes.binary_search(node).unwrap());
+
+        let mut out = String::from(
+            "\\begin{tikzpicture}[>=stealth, every node/.style={draw, circle, minimum size=6mm}]\n",
+        );
+        for node in &nodes {
+            let (x, y) = layout.positions[node];
+            out.push_str(&format!(
+                "  \\node ({}) at ({:.2}, {:.2}) {{{}}};\n",
+                id(node),
+                x * WIDTH,
+                (1.0 - y) * HEIGHT,
+                latex_escape(node)
+            ));
+        }
+
+        let al = self.get_adjacency_list();
+        let mut edges = self.get_edges();
+        edges.sort();
+        for (u, v, weight) in &edges {
+            if !self.is_oriented && u > v {
+                continue;
+            }
+            let mut style = Vec::new();
+            if self.is_oriented {
+                style.push("->");
+            }
+            if emphasis.contains(&(u.to_string(), v.to_string()))
+                || !self.is_oriented && emphasis.contains(&(v.to_string(), u.to_string()))
+            {
+                style.push("very thick, red");
+            }
+            let path = if u == v {
+                "to[loop above]"
+            } else if self.is_oriented && al[v].contains_key(u) {
+                // Opposite edges would lie on top of each other
+                "to[bend left=15]"
+            } else {
+                "--"
+            };
+            let label = weight.map_or(String::new(), |w| {
+                format!(" node[midway, draw=none, fill=white, font=\\small] {{{w}}}")
+            });
+            let style = if style.is_empty() {
+                String::new()
+            } else {
+                format!("[{}]", style.join(", "))
+            };
+            out.push_str(&format!(
+                "  \\draw{style} ({}) {path}{label} ({});\n",
+                id(u),
+                id(v)
+            ));
+        }
+        out.push_str("\\end{tikzpicture}");
+
+        out
+    }
+}
@@ -0,0 +1,348 @@
+use crate::algorithms::components;
+use crate::quiz::Verdict;
+use crate::runner::{Task, TaskOutput};
+use crate::script::split_words;
+use crate::*;
+
+use std::collections::BTreeSet;
+
+/// Tasks whose answers can be graded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradedTask {
+    /// Shortest in terms of number of edges paths from a node (task 2.2).
+    UnweightedPaths,
+    /// Minimum spanning tree (task 3).
+    Mst,
+    /// Strongly connected components (task 2.1).
+    StronglyConnected,
+    /// Value of maximum flow (task 5).
+    MaxFlow,
+}
+
+impl GradedTask {
+    pub const ALL: [GradedTask; 4] = [
+        GradedTask::UnweightedPaths,
+        GradedTask::Mst,
+        GradedTask::StronglyConnected,
+        GradedTask::MaxFlow,
+    ];
+
+    /// Short name used in the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GradedTask::UnweightedPaths => "task22",
+            GradedTask::Mst => "mst",
+            GradedTask::StronglyConnected => "scc",
+            GradedTask::MaxFlow => "maxflow",
+        }
+    }
+
+    /// Find task by its short name or by number of the task it checks, e.g.
+    /// both `scc` and `task21` work.
+    pub fn from_name(name: &str) -> GraphResult<Self> {
+        let task = match name {
+            "task22" | "unweighted-paths" => GradedTask::UnweightedPaths,
+            "task3" | "mst" | "kruskal" | "prim" => GradedTask::Mst,
+            "task21" | "scc" => GradedTask::StronglyConnected,
+            "task5" | "maxflow" => GradedTask::MaxFlow,
+            _ => {
+                return Err(GraphError {
+                    msg: format!("Unknown task to grade {name:?}"),
+                })
+            }
+        };
+        Ok(task)
+    }
+
+    /// Task finding the correct answer.
+    fn task(&self) -> Task {
+        match self {
+            GradedTask::UnweightedPaths => Task::UnweightedPaths,
+            GradedTask::Mst => Task::Kruskal,
+            GradedTask::StronglyConnected => Task::StronglyConnected,
+            GradedTask::MaxFlow => Task::MaxFlow,
+        }
+    }
+
+    /// Names of nodes task expects as arguments.
+    pub fn args(&self) -> &'static [&'static str] {
+        self.task().args()
+    }
+
+    /// Check answer to the task on the graph. Order of lines, components
+    /// and edges does not matter, and any optimal answer is accepted.
+    ///
+    /// Answers are read in the same formats the program prints them, but
+    /// quotes, brackets, commas and arrows may be left out:
+    /// - `task22`: line `NODE: A B C` (or `NODE: Unreachable`) per node;
+    /// - `mst`: an edge per line as `A B 3` or `A - B: 3`, weights are
+    ///   optional and names with spaces or dashes can be quoted;
+    /// - `scc`: a component per line or components separated by `;`;
+    /// - `maxflow`: a number.
+    pub fn grade(&self, gr: &Graph, args: &[String], answer: &str) -> GraphResult<Verdict> {
+        let expected = match self {
+            GradedTask::StronglyConnected => {
+                if !gr.is_oriented() {
+                    return Err(GraphError {
+                        msg: "Graph has to be oriented".to_string(),
+                    });
+                }
+                return Ok(grade_components(gr, answer));
+            }
+            _ => self.task().run(gr, args)?,
+        };
+
+        Ok(match expected {
+            TaskOutput::UnweightedPaths(paths) => grade_paths(gr, &args[0], &paths, answer),
+            TaskOutput::Tree(tree) => grade_tree(gr, &tree, answer),
+            TaskOutput::Flow(flow) => grade_flow(flow, answer),
+            _ => unreachable!(),
+        })
+    }
+}
+
+impl fmt::Display for GradedTask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Split answer into node names and numbers, skipping punctuation.
+fn words(s: &str) -> Vec<String> {
+    s.replace("->", " ")
+        .replace(" - ", " ")
+        .split(|c: char| c.is_whitespace() || ",;:\"'()[]{}".contains(c))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_string())
+        .collect()
+}
+
+/// Split line of an answer into words like [`split_words`] does, also
+/// taking punctuation outside of quotes and lone dashes and arrows as
+/// separators.
+fn quoted_words(line: &str) -> GraphResult<Vec<String>> {
+    let mut spaced = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_quotes => {
+                spaced.push(c);
+                spaced.extend(chars.next());
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                spaced.push(c);
+            }
+            c if !in_quotes && ",;:()[]{}".contains(c) => spaced.push(' '),
+            c => spaced.push(c),
+        }
+    }
+    let mut words = split_words(&spaced)?;
+    words.retain(|w| !["-", "–", "->", "→"].contains(&w.as_str()));
+    Ok(words)
+}
+
+fn join(nodes: &BTreeSet<String>) -> String {
+    nodes.iter().cloned().collect::<Vec<_>>().join(" ")
+}
+
+fn grade_paths(
+    gr: &Graph,
+    start: &str,
+    expected: &HashMap<String, Vec<String>>,
+    answer: &str,
+) -> Verdict {
+    let al = gr.get_adjacency_list();
+    let mut given = HashMap::new();
+    for line in answer.lines().filter(|l| !l.trim().is_empty()) {
+        let Some((to, path)) = line.split_once(':') else {
+            return Verdict::wrong(format!("Line {:?} has no `:` after the node", line.trim()));
+        };
+        let to = to.trim().trim_matches('"').to_string();
+        if !al.contains_key(&to) {
+            return Verdict::wrong(format!("There is no node {to:?}"));
+        }
+        let mut path = words(path);
+        if path.len() == 1 && path[0].eq_ignore_ascii_case("unreachable") {
+            path.clear();
+        }
+        if given.insert(to.clone(), path).is_some() {
+            return Verdict::wrong(format!("Path to {to} is given twice"));
+        }
+    }
+
+    let mut nodes = expected.keys().collect::<Vec<_>>();
+    nodes.sort();
+    for to in nodes {
+        let Some(path) = given.get(to) else {
+            return Verdict::wrong(format!("Path to {to} is missing"));
+        };
+        let shortest = &expected[to];
+        match (path.is_empty(), shortest.is_empty()) {
+            (true, true) => continue,
+            (true, false) => return Verdict::wrong(format!("{to} is reachable from {start}")),
+            (false, true) => return Verdict::wrong(format!("{to} is unreachable from {start}")),
+            (false, false) => (),
+        }
+        if path.first().map(|s| s.as_str()) != Some(start) || path.last() != Some(to) {
+            return Verdict::wrong(format!(
+                "Path to {to} has to start at {start} and end at {to}"
+            ));
+        }
+        if let Some(w) = path
+            .windows(2)
+            .find(|w| !al.get(&w[0]).is_some_and(|c| c.contains_key(&w[1])))
+        {
+            return Verdict::wrong(format!("There is no edge from {} to {}", w[0], w[1]));
+        }
+        if path.len() != shortest.len() {
+            return Verdict::wrong(format!(
+                "Path to {to} has {} edge(s), but there is one with {}",
+                path.len() - 1,
+                shortest.len() - 1
+            ));
+        }
+    }
+
+    Verdict::correct()
+}
+
+fn grade_tree(gr: &Graph, expected: &[EdgeWeighted], answer: &str) -> Verdict {
+    let al = gr.get_adjacency_list();
+    let mut edges = Vec::new();
+    for line in answer.lines() {
+        // Program prints total weight after the edges, it is not an edge
+        if line.trim().is_empty() || line.trim_start().to_lowercase().starts_with("total") {
+            continue;
+        }
+        let words = match quoted_words(line) {
+            Ok(words) => words,
+            Err(e) => return Verdict::wrong(format!("Line {:?}: {}", line.trim(), e.msg)),
+        };
+        let (from, to, given) = match &words[..] {
+            [from, to] => (from, to, None),
+            [from, to, weight] => match weight.parse::<EdgeWeight>() {
+                Ok(w) => (from, to, Some(w)),
+                Err(_) => return Verdict::wrong(format!("{weight:?} is not a weight")),
+            },
+            _ => {
+                return Verdict::wrong(format!(
+                    "Line {:?} is not an edge of two nodes and an optional weight",
+                    line.trim()
+                ))
+            }
+        };
+        if let Some(node) = [from, to].into_iter().find(|n| !al.contains_key(*n)) {
+            return Verdict::wrong(format!("There is no node {node:?}"));
+        }
+        let Some(weight) = al[from].get(to).copied().flatten() else {
+            return Verdict::wrong(format!("There is no edge between {from} and {to}"));
+        };
+        if let Some(given) = given.filter(|g| *g != weight) {
+            return Verdict::wrong(format!(
+                "Edge between {from} and {to} weighs {weight}, not {given}"
+            ));
+        }
+        edges.push((from.to_string(), to.to_string(), weight));
+    }
+
+    let mut tree_id = gr
+        .get_nodes()
+        .into_iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect::<HashMap<_, _>>();
+    for (from, to, _) in &edges {
+        let (old_id, new_id) = (tree_id[from], tree_id[to]);
+        if old_id == new_id {
+            return Verdict::wrong(format!("Edge between {from} and {to} makes a cycle"));
+        }
+        for id in tree_id.values_mut() {
+            if *id == old_id {
+                *id = new_id;
+            }
+        }
+    }
+    if edges.len() < expected.len() {
+        return Verdict::wrong(format!(
+            "Tree does not span all nodes: it has {} edge(s) instead of {}",
+            edges.len(),
+            expected.len()
+        ));
+    }
+
+    let weight = edges.iter().map(|e| e.2).sum::<EdgeWeight>();
+    let optimal = expected.iter().map(|e| e.2).sum::<EdgeWeight>();
+    if weight != optimal {
+        return Verdict::wrong(format!(
+            "Tree weighs {weight}, but there is one weighing {optimal}"
+        ));
+    }
+
+    Verdict::correct()
+}
+
+fn grade_components(gr: &Graph, answer: &str) -> Verdict {
+    let al = gr.get_adjacency_list();
+    let expected = components::strongly_connected(gr);
+    let component_of = |node: &String| &expected.components[expected.component_of[node]];
+
+    let mut seen = HashSet::new();
+    let mut given = Vec::new();
+    for component in answer.split(['\n', ';']) {
+        let component = words(component).into_iter().collect::<BTreeSet<_>>();
+        if component.is_empty() {
+            continue;
+        }
+        for node in &component {
+            if !al.contains_key(node) {
+                return Verdict::wrong(format!("There is no node {node:?}"));
+            }
+            if !seen.insert(node.to_string()) {
+                return Verdict::wrong(format!("{node} is in more than one component"));
+            }
+        }
+        given.push(component);
+    }
+
+    for component in &given {
+        let first = component.iter().next().unwrap();
+        let whole = component_of(first);
+        if let Some(other) = component.iter().find(|n| !whole.contains(*n)) {
+            return Verdict::wrong(format!(
+                "{first} and {other} are not reachable from each other"
+            ));
+        }
+        if component != whole {
+            return Verdict::wrong(format!(
+                "{{{}}} is only a part of component {{{}}}",
+                join(component),
+                join(whole)
+            ));
+        }
+    }
+    let mut missing = gr.get_nodes();
+    missing.retain(|n| !seen.contains(n));
+    if !missing.is_empty() {
+        return Verdict::wrong(format!("Nodes {} are missing", missing.join(" ")));
+    }
+
+    Verdict::correct()
+}
+
+fn grade_flow(expected: EdgeWeight, answer: &str) -> Verdict {
+    // Take the last number, so that e.g. `Maximum flow: 11` works too
+    let number = words(answer)
+        .into_iter()
+        .rev()
+        .find_map(|w| w.parse::<EdgeWeight>().ok());
+    match number {
+        None => Verdict::wrong("Answer has no number in it".to_string()),
+        Some(flow) if flow == expected => Verdict::correct(),
+        Some(flow) if flow < expected => {
+            Verdict::wrong(format!("There is a flow greater than {flow}"))
+        }
+        Some(flow) => Verdict::wrong(format!("No flow is as great as {flow}")),
+    }
+}
@@ -0,0 +1,213 @@
+use crate::*;
+
+/// Change made to a graph, with enough data to revert it.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    PushNode(String),
+    PopNode {
+        node: String,
+        outgoing: HashMap<String, Option<EdgeWeight>>,
+        incoming: Vec<(String, Option<EdgeWeight>)>,
+    },
+    PushEdge {
+        from: String,
+        to: String,
+        weight: Option<EdgeWeight>,
+        /// Weight of the edge it replaced, if there was one.
+        previous: Option<Option<EdgeWeight>>,
+    },
+    PopEdge {
+        from: String,
+        to: String,
+        weight: Option<EdgeWeight>,
+    },
+    /// Whole graph was replaced, e.g. after changing its properties.
+    Replace {
+        before: Graph,
+        after: Graph,
+    },
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::PushNode(node) => write!(f, "push node {node:?}"),
+            Operation::PopNode { node, .. } => write!(f, "pop node {node:?}"),
+            Operation::PushEdge { from, to, .. } => write!(f, "connect {from:?} with {to:?}"),
+            Operation::PopEdge { from, to, .. } => write!(f, "disconnect {from:?} with {to:?}"),
+            Operation::Replace { .. } => write!(f, "replace graph"),
+        }
+    }
+}
+
+/// Graph together with log of operations made on it, which can be undone
+/// and redone.
+#[derive(Debug, Clone)]
+pub struct History {
+    graph: Graph,
+    done: Vec<Operation>,
+    undone: Vec<Operation>,
+}
+
+impl History {
+    pub fn new(graph: Graph) -> Self {
+        Self {
+            graph,
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// Get current state of the graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Get operations which can be undone, the latest is the last one.
+    pub fn done(&self) -> &[Operation] {
+        &self.done
+    }
+
+    /// Add new node to the graph.
+    pub fn push_node(&mut self, name: String) -> GraphResult<String> {
+        self.graph.push_node(name.clone())?;
+        self.record(Operation::PushNode(name.clone()));
+        Ok(name)
+    }
+
+    /// Remove node from the graph.
+    pub fn pop_node(&mut self, node: String) -> GraphResult<HashMap<String, Option<EdgeWeight>>> {
+        let incoming = self
+            .graph
+            .get_edges()
+            .into_iter()
+            .filter(|(from, to, _)| *to == node && *from != node)
+            .map(|(from, _, weight)| (from, weight))
+            .collect();
+        let outgoing = self.graph.pop_node(node.clone())?;
+        self.record(Operation::PopNode {
+            node,
+            outgoing: outgoing.clone(),
+            incoming,
+        });
+        Ok(outgoing)
+    }
+
+    /// Add new edge to the graph.
+    pub fn push_edge(
+        &mut self,
+        from: String,
+        to: String,
+        weight: Option<EdgeWeight>,
+    ) -> GraphResult<()> {
+        let previous = self
+            .graph
+            .adjacency_list
+            .get(&from)
+            .and_then(|c| c.get(&to))
+            .cloned();
+        self.graph.push_edge(from.clone(), to.clone(), weight)?;
+        self.record(Operation::PushEdge {
+            from,
+            to,
+            weight,
+            previous,
+        });
+        Ok(())
+    }
+
+    /// Remove an edge from graph.
+    pub fn pop_edge(&mut self, from: String, to: String) -> GraphResult<Option<EdgeWeight>> {
+        let weight = self.graph.pop_edge(from.clone(), to.clone())?;
+        self.record(Operation::PopEdge { from, to, weight });
+        Ok(weight)
+    }
+
+    /// Replace the whole graph.
+    pub fn replace(&mut self, graph: Graph) {
+        let before = std::mem::replace(&mut self.graph, graph.clone());
+        self.record(Operation::Replace {
+            before,
+            after: graph,
+        });
+    }
+
+    /// Revert the latest operation. Returns it, or `None` if there is
+    /// nothing to undo. If reverting fails, history stays the same.
+    pub fn undo(&mut self) -> GraphResult<Option<Operation>> {
+        let Some(op) = self.done.last() else {
+            return Ok(None);
+        };
+        // Operation may fail half way, so it is done on a copy
+        let mut graph = self.graph.clone();
+        match op {
+            Operation::PushNode(node) => {
+                graph.pop_node(node.clone())?;
+            }
+            Operation::PopNode {
+                node,
+                outgoing,
+                incoming,
+            } => {
+                graph.push_node(node.clone())?;
+                for (to, weight) in outgoing {
+                    graph.push_edge(node.clone(), to.clone(), *weight)?;
+                }
+                for (from, weight) in incoming {
+                    graph.push_edge(from.clone(), node.clone(), *weight)?;
+                }
+            }
+            Operation::PushEdge {
+                from, to, previous, ..
+            } => match previous {
+                Some(weight) => graph.push_edge(from.clone(), to.clone(), *weight)?,
+                None => {
+                    graph.pop_edge(from.clone(), to.clone())?;
+                }
+            },
+            Operation::PopEdge { from, to, weight } => {
+                graph.push_edge(from.clone(), to.clone(), *weight)?
+            }
+            Operation::Replace { before, .. } => graph = before.clone(),
+        }
+        self.graph = graph;
+        let op = self.done.pop().unwrap();
+        self.undone.push(op.clone());
+
+        Ok(Some(op))
+    }
+
+    /// Repeat the latest undone operation. Returns it, or `None` if there is
+    /// nothing to redo. If repeating fails, history stays the same.
+    pub fn redo(&mut self) -> GraphResult<Option<Operation>> {
+        let Some(op) = self.undone.last() else {
+            return Ok(None);
+        };
+        let mut graph = self.graph.clone();
+        match op {
+            Operation::PushNode(node) => {
+                graph.push_node(node.clone())?;
+            }
+            Operation::PopNode { node, .. } => {
+                graph.pop_node(node.clone())?;
+            }
+            Operation::PushEdge {
+                from, to, weight, ..
+            } => graph.push_edge(from.clone(), to.clone(), *weight)?,
+            Operation::PopEdge { from, to, .. } => {
+                graph.pop_edge(from.clone(), to.clone())?;
+            }
+            Operation::Replace { after, .. } => graph = after.clone(),
+        }
+        self.graph = graph;
+        let op = self.undone.pop().unwrap();
+        self.done.push(op.clone());
+
+        Ok(Some(op))
+    }
+
+    fn record(&mut self, op: Operation) {
+        self.done.push(op);
+        self.undone.clear();
+    }
+}
@@ -0,0 +1,233 @@
+use crate::history::Operation;
+use crate::script::{quote, split_words, Interpreter};
+use crate::workspace::Workspace;
+use crate::*;
+
+use std::collections::BTreeMap;
+use std::fs::{OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = "# graphs-at-ssu recovery journal";
+
+/// Log of changes made to graphs of a workspace, written to a file as they
+/// happen so that session can be restored after a crash.
+///
+/// Every line is a command of the script language prefixed with name of the
+/// graph it applies to, e.g. `@travel connect Home Park 5`. Two more
+/// commands are used: `activate` makes the graph active and `drop` removes
+/// it from the workspace.
+///
+/// Journal file is locked while the session runs, so a second session
+/// cannot write to it or restore from it at the same time.
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    file: File,
+    /// State of every graph as it is recorded in the journal, together with
+    /// number of operations in its history.
+    recorded: BTreeMap<String, (Graph, usize)>,
+    active: String,
+}
+
+impl Journal {
+    /// Default location of the journal in the home directory of the user.
+    pub fn default_path() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".graphs-at-ssu_recovery"))
+    }
+
+    /// Start new journal, overwriting the old one, with current state of
+    /// the workspace. Fails if another session uses the journal.
+    pub fn start(path: &Path, ws: &Workspace) -> GraphResult<Self> {
+        // Journal is truncated only after it is locked, so that journal of
+        // a running session is left alone
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        file.try_lock().map_err(|e| lock_error(path, e))?;
+        file.set_len(0)?;
+
+        let mut journal = Self {
+            path: path.to_path_buf(),
+            file,
+            recorded: BTreeMap::new(),
+            active: String::new(),
+        };
+        journal.write(&[HEADER.to_string()])?;
+        journal.sync(ws)?;
+
+        Ok(journal)
+    }
+
+    /// Record every change made to the workspace since the previous call.
+    /// Graph changed by a single new operation gets only that operation
+    /// recorded, otherwise its whole new state is recorded.
+    pub fn sync(&mut self, ws: &Workspace) -> GraphResult<()> {
+        let dropped = self
+            .recorded
+            .keys()
+            .filter(|name| ws.get(name).is_err())
+            .cloned()
+            .collect::<Vec<_>>();
+        for name in dropped {
+            self.write(&[format!("@{} drop", quote(&name))])?;
+            self.recorded.remove(&name);
+        }
+
+        for name in ws.names() {
+            let history = ws.history(&name)?;
+            let (gr, done) = (history.graph(), history.done());
+            match self.recorded.get(&name) {
+                Some((recorded, _)) if recorded == gr => {}
+                Some((_, count)) if done.len() == count + 1 => {
+                    self.record(&name, done.last().unwrap())?
+                }
+                _ => self.record_graph(&name, gr)?,
+            }
+            self.recorded.insert(name, (gr.clone(), done.len()));
+        }
+
+        if self.active != ws.active_name() {
+            self.active = ws.active_name().to_string();
+            self.write(&[format!("@{} activate", quote(&self.active))])?;
+        }
+
+        Ok(())
+    }
+
+    /// Record operation made on graph `name`.
+    fn record(&mut self, name: &str, op: &Operation) -> GraphResult<()> {
+        let line = |cmd: &str, args: &[&String]| {
+            let mut line = format!("@{} {cmd}", quote(name));
+            for arg in args {
+                line.push(' ');
+                line.push_str(&quote(arg));
+            }
+            line
+        };
+        match op {
+            Operation::PushNode(node) => self.write(&[line("add-node", &[node])]),
+            Operation::PopNode { node, .. } => self.write(&[line("remove-node", &[node])]),
+            Operation::PushEdge {
+                from, to, weight, ..
+            } => match weight {
+                Some(w) => self.write(&[line("connect", &[from, to, &w.to_string()])]),
+                None => self.write(&[line("connect", &[from, to])]),
+            },
+            Operation::PopEdge { from, to, .. } => self.write(&[line("disconnect", &[from, to])]),
+            Operation::Replace { after, .. } => self.record_graph(name, after),
+        }
+    }
+
+    /// Record that graph `name` now is exactly `gr`.
+    fn record_graph(&mut self, name: &str, gr: &Graph) -> GraphResult<()> {
+        let prefix = format!("@{} ", quote(name));
+        let mut lines = vec![format!("{prefix}new {}", gr.description())];
+        for node in gr.get_nodes() {
+            lines.push(format!("{prefix}add-node {}", quote(&node)));
+        }
+        let mut edges = gr.get_edges();
+        edges.sort();
+        for (from, to, weight) in edges {
+            if !gr.is_oriented() && from > to {
+                continue;
+            }
+            let mut line = format!("{prefix}connect {} {}", quote(&from), quote(&to));
+            if let Some(w) = weight {
+                line.push_str(&format!(" {w}"));
+            }
+            lines.push(line);
+        }
+        self.write(&lines)
+    }
+
+    /// Remove the journal after session was finished properly.
+    pub fn finish(self) -> GraphResult<()> {
+        drop(self.file);
+        std::fs::remove_file(&self.path)?;
+        Ok(())
+    }
+
+    fn write(&mut self, lines: &[String]) -> GraphResult<()> {
+        for line in lines {
+            writeln!(self.file, "{line}")?;
+        }
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    /// Restore workspace by replaying journal left by unfinished session.
+    /// Returns `None` if there is no journal at `path`, and fails if the
+    /// session is still running.
+    pub fn recover(path: &Path) -> GraphResult<Option<Workspace>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut file = File::open(path)?;
+        file.try_lock_shared().map_err(|e| lock_error(path, e))?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        Self::replay(&contents).map(Some)
+    }
+
+    /// Build workspace by replaying journal contents.
+    pub fn replay(contents: &str) -> GraphResult<Workspace> {
+        let mut graphs = BTreeMap::<String, Interpreter>::new();
+        let mut active = None;
+
+        for (i, line) in contents.lines().enumerate() {
+            let words = split_words(line)?;
+            let Some((target, cmd)) = words.split_first() else {
+                continue;
+            };
+            if target.starts_with('#') {
+                continue;
+            }
+            let error = |msg: String| GraphError {
+                msg: format!("Broken journal on line {}: {msg}", i + 1),
+            };
+            let name = target
+                .strip_prefix('@')
+                .ok_or_else(|| error("graph name expected".to_string()))?;
+
+            match cmd.first().map(|c| c.as_str()) {
+                Some("activate") => active = Some(name.to_string()),
+                Some("drop") => {
+                    graphs.remove(name);
+                }
+                _ => {
+                    let cmd = cmd.iter().map(|w| quote(w)).collect::<Vec<_>>().join(" ");
+                    graphs
+                        .entry(name.to_string())
+                        .or_insert_with(|| Interpreter::new(Graph::new(false, false)))
+                        .execute(&cmd)
+                        .map_err(|e| error(e.msg))?;
+                }
+            }
+        }
+
+        let mut graphs = graphs.into_iter();
+        let (first, interpreter) = graphs.next().ok_or_else(|| GraphError {
+            msg: "Journal does not contain any graph".to_string(),
+        })?;
+        let mut ws = Workspace::new(first, interpreter.graph);
+        for (name, interpreter) in graphs {
+            ws.insert(name, interpreter.graph)?;
+        }
+        if let Some(active) = active {
+            ws.switch(&active)?;
+        }
+
+        Ok(ws)
+    }
+}
+
+fn lock_error(path: &Path, e: TryLockError) -> GraphError {
+    match e {
+        TryLockError::WouldBlock => GraphError {
+            msg: format!("Journal {path:?} is used by another running session"),
+        },
+        TryLockError::Error(e) => e.into(),
+    }
+}
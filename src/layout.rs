@@ -0,0 +1,211 @@
+use crate::*;
+
+use std::f64::consts::PI;
+
+/// Positions of graph nodes on a plane, both coordinates are in `[0, 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub positions: HashMap<String, (f64, f64)>,
+}
+
+impl Layout {
+    /// Choose layout suitable for the graph: layered one for oriented graphs
+    /// without cycles and force-directed for everything else.
+    pub fn auto(gr: &Graph) -> Self {
+        Self::layered(gr).unwrap_or_else(|_| Self::force_directed(gr))
+    }
+
+    /// Find layout by its name: `auto`, `layered`, `circular` or `force`.
+    pub fn from_name(name: &str, gr: &Graph) -> GraphResult<Self> {
+        match name {
+            "auto" => Ok(Self::auto(gr)),
+            "layered" => Self::layered(gr),
+            "circular" => Ok(Self::circular(gr)),
+            "force" => Ok(Self::force_directed(gr)),
+            _ => Err(GraphError {
+                msg: format!("Unknown layout {name:?}"),
+            }),
+        }
+    }
+
+    /// Put nodes on a circle in alphabetical order.
+    pub fn circular(gr: &Graph) -> Self {
+        let nodes = gr.get_nodes();
+        let n = nodes.len() as f64;
+        let positions = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| {
+                if n < 2.0 {
+                    return (node, (0.5, 0.5));
+                }
+                let angle = 2.0 * PI * i as f64 / n - PI / 2.0;
+                (node, (0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()))
+            })
+            .collect();
+
+        Self { positions }
+    }
+
+    /// Place nodes by simulating repulsion between all of them and
+    /// attraction along edges (Fruchterman–Reingold), starting from the
+    /// circular layout so the result is always the same.
+    pub fn force_directed(gr: &Graph) -> Self {
+        const ITERATIONS: usize = 300;
+        const GRAVITY: f64 = 4.0;
+
+        let nodes = gr.get_nodes();
+        let n = nodes.len();
+        if n < 2 {
+            return Self::circular(gr);
+        }
+        let start = Self::circular(gr);
+        let mut pos = nodes
+            .iter()
+            .map(|node| start.positions[node])
+            .collect::<Vec<_>>();
+        let index = |node: &String| nodes.binary_search(node).unwrap();
+        let mut edges = gr
+            .get_edges()
+            .iter()
+            .filter(|(u, v, _)| u != v)
+            .map(|(u, v, _)| (index(u).min(index(v)), index(u).max(index(v))))
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges.dedup();
+
+        // Ideal distance between nodes
+        let k = (1.0 / n as f64).sqrt();
+        for iteration in 0..ITERATIONS {
+            let mut shift = vec![(0.0, 0.0); n];
+            for i in 0..n {
+                for j in i + 1..n {
+                    let (dx, dy) = (pos[i].0 - pos[j].0, pos[i].1 - pos[j].1);
+                    let d = (dx * dx + dy * dy).sqrt().max(1e-3);
+                    let f = k * k / d;
+                    shift[i].0 += dx / d * f;
+                    shift[i].1 += dy / d * f;
+                    shift[j].0 -= dx / d * f;
+                    shift[j].1 -= dy / d * f;
+                }
+            }
+            for &(i, j) in &edges {
+                let (dx, dy) = (pos[i].0 - pos[j].0, pos[i].1 - pos[j].1);
+                let d = (dx * dx + dy * dy).sqrt().max(1e-3);
+                let f = d * d / k;
+                shift[i].0 -= dx / d * f;
+                shift[i].1 -= dy / d * f;
+                shift[j].0 += dx / d * f;
+                shift[j].1 += dy / d * f;
+            }
+            // Gravity keeps disconnected parts together
+            for (i, p) in pos.iter().enumerate() {
+                shift[i].0 -= (p.0 - 0.5) * k * GRAVITY;
+                shift[i].1 -= (p.1 - 0.5) * k * GRAVITY;
+            }
+
+            // Nodes move less and less as the layout cools down
+            let temperature = 0.1 * (1.0 - iteration as f64 / ITERATIONS as f64);
+            for (p, (sx, sy)) in pos.iter_mut().zip(shift) {
+                let len = (sx * sx + sy * sy).sqrt();
+                if len > 0.0 {
+                    p.0 += sx / len * len.min(temperature);
+                    p.1 += sy / len * len.min(temperature);
+                }
+            }
+        }
+
+        Self {
+            positions: nodes.into_iter().zip(normalize(&pos)).collect(),
+        }
+    }
+
+    /// Put nodes of oriented graph without cycles in layers from top to
+    /// bottom, so that every edge goes down. Nodes inside a layer are
+    /// ordered to keep edges short.
+    pub fn layered(gr: &Graph) -> GraphResult<Self> {
+        let order = algorithms::traversals::topological_sort(gr)?;
+        let al = gr.get_adjacency_list();
+
+        // Layer of a node is the length of the longest path leading to it
+        let mut layer_of = HashMap::<String, usize>::new();
+        for node in &order {
+            let layer = *layer_of.entry(node.to_string()).or_insert(0);
+            for to in al[node].keys() {
+                let l = layer_of.entry(to.to_string()).or_insert(0);
+                *l = (*l).max(layer + 1);
+            }
+        }
+        let layers_count = layer_of.values().max().map_or(0, |l| l + 1);
+        let mut layers = vec![Vec::<String>::new(); layers_count];
+        for node in gr.get_nodes() {
+            layers[layer_of[&node]].push(node);
+        }
+
+        // Order nodes by average position of their predecessors
+        let mut position = HashMap::<String, f64>::new();
+        for layer in layers.iter_mut() {
+            let barycenter = |node: &String| {
+                let parents = order
+                    .iter()
+                    .filter(|p| al[*p].contains_key(node) && position.contains_key(*p))
+                    .map(|p| position[p])
+                    .collect::<Vec<_>>();
+                if parents.is_empty() {
+                    f64::MAX
+                } else {
+                    parents.iter().sum::<f64>() / parents.len() as f64
+                }
+            };
+            let mut keyed = layer
+                .iter()
+                .map(|n| (barycenter(n), n.to_string()))
+                .collect::<Vec<_>>();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+            *layer = keyed.into_iter().map(|(_, n)| n).collect();
+            for (i, node) in layer.iter().enumerate() {
+                position.insert(node.to_string(), i as f64);
+            }
+        }
+
+        let mut positions = HashMap::new();
+        for (l, layer) in layers.iter().enumerate() {
+            for (i, node) in layer.iter().enumerate() {
+                let x = (i as f64 + 1.0) / (layer.len() as f64 + 1.0);
+                let y = if layers_count > 1 {
+                    l as f64 / (layers_count - 1) as f64
+                } else {
+                    0.5
+                };
+                positions.insert(node.to_string(), (x, y));
+            }
+        }
+
+        Ok(Self { positions })
+    }
+}
+
+/// Scale points to fit into `[0, 1]` keeping proportions.
+fn normalize(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let min_x = points.iter().map(|p| p.0).fold(f64::MAX, f64::min);
+    let max_x = points.iter().map(|p| p.0).fold(f64::MIN, f64::max);
+    let min_y = points.iter().map(|p| p.1).fold(f64::MAX, f64::min);
+    let max_y = points.iter().map(|p| p.1).fold(f64::MIN, f64::max);
+    let size = (max_x - min_x).max(max_y - min_y);
+    if size <= 0.0 {
+        return points.iter().map(|_| (0.5, 0.5)).collect();
+    }
+
+    // Center the picture along the shorter side
+    let offset_x = (size - (max_x - min_x)) / 2.0;
+    let offset_y = (size - (max_y - min_y)) / 2.0;
+    points
+        .iter()
+        .map(|p| {
+            (
+                (p.0 - min_x + offset_x) / size,
+                (p.1 - min_y + offset_y) / size,
+            )
+        })
+        .collect()
+}
@@ -4,7 +4,18 @@ use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
 pub mod algorithms;
+pub mod animation;
+pub mod formats;
+pub mod grader;
+pub mod history;
+pub mod journal;
+pub mod layout;
+pub mod properties;
+pub mod quiz;
+pub mod runner;
+pub mod script;
 pub mod tasks;
+pub mod workspace;
 
 /// Print an error message to the terminal without exiting the program.
 #[macro_export]
@@ -55,6 +66,8 @@ impl fmt::Display for GraphError {
     }
 }
 
+impl std::error::Error for GraphError {}
+
 impl From<std::io::Error> for GraphError {
     fn from(e: std::io::Error) -> Self {
         GraphError {
@@ -76,7 +89,7 @@ pub type EdgeWeight = i32;
 pub type Edge = (String, String, Option<EdgeWeight>);
 pub type EdgeWeighted = (String, String, EdgeWeight);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Graph {
     adjacency_list: HashMap<String, HashMap<String, Option<EdgeWeight>>>,
     is_weighted: bool,
@@ -112,17 +125,12 @@ impl Graph {
         self.is_oriented
     }
 
-    /// Create new graph from given file.
-    pub fn from_file(path: String) -> GraphResult<Self> {
-        let in_file = File::open(path)?;
-        let mut buf_reader = BufReader::new(in_file);
-
-        let mut graph_description = String::new();
-        buf_reader.read_line(&mut graph_description)?;
+    /// Create new empty graph from description like `not weighted oriented`.
+    pub fn from_description(description: &str) -> GraphResult<Self> {
         let mut not = false;
         let mut is_weighted = None;
         let mut is_oriented = None;
-        for word in graph_description.split_whitespace() {
+        for word in description.split_whitespace() {
             match word {
                 "not" => not = !not,
                 "oriented" => {
@@ -152,8 +160,22 @@ impl Graph {
                 ),
             });
         }
-        let is_weighted = is_weighted.unwrap();
-        let is_oriented = is_oriented.unwrap();
+
+        Ok(Self::new(is_weighted.unwrap(), is_oriented.unwrap()))
+    }
+
+    /// Create new graph from given file.
+    pub fn from_file(path: String) -> GraphResult<Self> {
+        let in_file = File::open(path)?;
+        let mut buf_reader = BufReader::new(in_file);
+
+        let mut graph_description = String::new();
+        buf_reader.read_line(&mut graph_description)?;
+        let Graph {
+            is_weighted,
+            is_oriented,
+            ..
+        } = Self::from_description(&graph_description)?;
 
         let mut adjacency_list: HashMap<String, HashMap<String, Option<EdgeWeight>>> =
             HashMap::new();
@@ -202,18 +224,25 @@ impl Graph {
         Ok(())
     }
 
-    /// Get a multiline string representing graph using adjacency list.
-    pub fn pretty_view(&self) -> String {
-        let mut al = String::new();
+    /// Get description of graph properties like `not weighted oriented`.
+    pub fn description(&self) -> String {
+        let mut description = String::new();
 
         if !self.is_weighted {
-            al.push_str("not ");
+            description.push_str("not ");
         }
-        al.push_str("weighted ");
+        description.push_str("weighted ");
         if !self.is_oriented {
-            al.push_str("not ");
+            description.push_str("not ");
         }
-        al.push_str("oriented");
+        description.push_str("oriented");
+
+        description
+    }
+
+    /// Get a multiline string representing graph using adjacency list.
+    pub fn pretty_view(&self) -> String {
+        let mut al = self.description();
 
         for node in self.get_nodes() {
             al.push_str(&format!("\n{}: ", &node));
@@ -291,8 +320,8 @@ impl Graph {
     pub fn get_nodes(&self) -> Vec<String> {
         let mut nodes = self
             .adjacency_list
-            .iter()
-            .map(|(node, _)| node.to_string())
+            .keys()
+            .map(|node| node.to_string())
             .collect::<Vec<_>>();
         nodes.sort();
 
@@ -1,95 +1,154 @@
+use graphs_at_ssu::animation::{Algorithm, Animation};
+use graphs_at_ssu::formats::ascii::path_edges;
+use graphs_at_ssu::journal::Journal;
+use graphs_at_ssu::layout::Layout;
+use graphs_at_ssu::properties::WeightMerge;
+use graphs_at_ssu::quiz::Random;
+use graphs_at_ssu::runner::Task;
+use graphs_at_ssu::workspace::Workspace;
 use graphs_at_ssu::*;
-use inquire::{error::InquireResult, Confirm, CustomType, CustomUserError, Select, Text};
+use inquire::{
+    error::InquireResult, Confirm, CustomType, CustomUserError, InquireError, Select, Text,
+};
+use std::collections::HashSet;
 use std::process;
 
+mod cli;
+mod exercise;
+mod player;
+mod repl;
+
 fn main() -> InquireResult<()> {
-    const TASK1A1: &str = "Ia. Find nodes which are adjacent from u, but aren't from v";
-    const TASK1A2: &str = "Ia. Find nodes which are adjacent from u and v";
-    const TASK1B: &str = "Ib. Inverse oriented graph";
-    const TASK21: &str = "II. Find strongly connected component in oriented graph";
-    const TASK22: &str = "II. Find shortest path from given node to others";
-    const TASK3KRUSKAL: &str = "III. Find MST using Kruskal algorithm";
-    const TASK3PRIM: &str = "III. Find MST using Prim algorithm";
-    const TASK4A: &str = "IVa. Find shortest paths for every pair of nodes";
-    const TASK4B: &str = "IVb. Find shortest paths from u to others.";
-    const TASK4C: &str = "IVc. Find negative cycle.";
-    const EDMONDS_KARP: &str = "V. Find maximum flow using Edmonds–Karp algorithm";
-    const HELPER_DFS: &str = "Get DFS from certain node";
-    const HELPER_BFS: &str = "Get BFS from certain node";
-    const HELPER_DIJKSTRA: &str = "Find shortest paths from one node to others using Dijkstra";
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if !args.is_empty() {
+        process::exit(cli::run(args));
+    }
 
-    let tasks = vec![
-        TASK1A1,
-        TASK1A2,
-        TASK1B,
-        TASK21,
-        TASK22,
-        TASK3KRUSKAL,
-        TASK3PRIM,
-        TASK4A,
-        TASK4B,
-        TASK4C,
-        EDMONDS_KARP,
-        HELPER_DFS,
-        HELPER_BFS,
-        HELPER_DIJKSTRA,
-    ];
-    print!("\x1B[2J\x1B[1;1H"); // clear the console
-    let graph_creation_ans = or_err!(Select::new(
-        "What do you want to start with?",
-        vec!["Create new graph", "Load from file"],
-    )
-    .prompt());
+    interactive()
+}
 
-    let mut gr = if graph_creation_ans == "Load from file" {
-        let file_path = or_err!(Text::new("Select graph file:")
-            .with_suggester(&suggest_file_paths)
-            .with_completer(&complete_file_path)
-            .with_initial_value("./")
-            .prompt());
-        or_err!(Graph::from_file(file_path))
-    } else {
-        let is_weighted_ans = or_err!(Confirm::new("Do you want weighted graph?")
-            .with_default(false)
-            .prompt());
-        let is_oriented_ans = or_err!(Confirm::new("Do you want oriented graph?")
-            .with_default(false)
-            .prompt());
-        Graph::new(is_weighted_ans, is_oriented_ans)
+/// Run menu-driven interactive mode.
+fn interactive() -> InquireResult<()> {
+    let mut json_output = false;
+
+    print!("\x1B[2J\x1B[1;1H"); // clear the console
+    let journal_path = Journal::default_path();
+    let recovered = match journal_path.as_deref().map(Journal::recover) {
+        Some(Ok(ws)) => ws,
+        Some(Err(e)) => {
+            safe_err!("Cannot restore previous session: {e}");
+            None
+        }
+        None => None,
     };
+    let restore = recovered.is_some()
+        && or_err!(
+            Confirm::new("Previous session was not finished properly. Restore it?")
+                .with_default(true)
+                .prompt()
+        );
+    let mut ws = match recovered {
+        Some(ws) if restore => ws,
+        _ => {
+            let (name, gr) = or_err!(prompt_graph());
+            Workspace::new(name, gr)
+        }
+    };
+    let mut journal = journal_path.and_then(|path| match Journal::start(&path, &ws) {
+        Ok(journal) => Some(journal),
+        Err(e) => {
+            safe_err!("Cannot start recovery journal, changes will not be saved: {e}");
+            None
+        }
+    });
     println!();
 
     loop {
-        // TODO: Change properties from interface
+        if let Some(j) = &mut journal {
+            if let Err(e) = j.sync(&ws) {
+                safe_err!("Cannot write recovery journal, changes will not be saved: {e}");
+                journal = None;
+            }
+        }
+
         let cmd_ans = match Select::new(
-            "What to do...?",
+            &format!("What to do with {:?}...?", ws.active_name()),
             vec![
                 "Look at graph",
+                "Draw graph",
                 "Push new node",
                 "Pop existing node",
                 "Connect two nodes",
                 "Disconnect two nodes",
+                "Undo",
+                "Redo",
                 "Save to file",
+                "Change properties...",
+                if json_output {
+                    "Switch to text output"
+                } else {
+                    "Switch to JSON output"
+                },
                 "Tasks...",
+                "Animate algorithm...",
+                "Quiz...",
+                "Graphs...",
+                "Command line...",
             ],
         )
         .prompt()
         {
             Ok(cmd) => cmd,
-            Err(_) => break,
+            Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => break,
+            // Journal is kept, so session can be restored next time
+            Err(e) => return Err(e),
         };
+        let history = ws.active_mut();
+        let gr = history.graph();
 
         match cmd_ans {
+            "Look at graph" if json_output => println!("\n{}", gr.to_json()),
             "Look at graph" => println!("\n{}", gr.pretty_view()),
+            "Draw graph" => {
+                let options = vec!["Nothing", "Shortest path", "Minimum spanning tree"];
+                let highlight = match or_escape!(Select::new("Highlight:", options).prompt()) {
+                    "Shortest path" => {
+                        let nodes = gr.get_nodes();
+                        let from =
+                            or_escape!(Select::new("Select node from:", nodes.clone()).prompt());
+                        let to = or_escape!(Select::new("Select node to:", nodes).prompt());
+                        match algorithms::weighted::dijkstra_convenient(gr, from) {
+                            Ok(paths) if paths[&to].0.is_some() => path_edges(&paths[&to].1),
+                            Ok(_) => {
+                                safe_err!("There is no path to {to:?}");
+                                continue;
+                            }
+                            Err(e) => {
+                                safe_err!("Cannot find shortest path: {e}");
+                                continue;
+                            }
+                        }
+                    }
+                    "Minimum spanning tree" => match algorithms::mst::kruskal(gr) {
+                        Ok(mst) => mst.into_iter().map(|(u, v, _)| (u, v)).collect(),
+                        Err(e) => {
+                            safe_err!("Cannot find minimum spanning tree: {e}");
+                            continue;
+                        }
+                    },
+                    _ => HashSet::new(),
+                };
+                println!("\n{}", gr.to_ascii(&Layout::auto(gr), &highlight, true));
+            }
             "Push new node" => {
                 let node_name = or_escape!(Text::new("Enter node name:").prompt());
-                if let Err(e) = gr.push_node(node_name) {
+                if let Err(e) = history.push_node(node_name) {
                     safe_err!("Cannot push node: {e}");
                 }
             }
             "Pop existing node" => {
                 let node_name = or_escape!(Select::new("Select node:", gr.get_nodes()).prompt());
-                if let Err(e) = gr.pop_node(node_name) {
+                if let Err(e) = history.pop_node(node_name) {
                     safe_err!("Cannot pop node: {e}");
                 }
             }
@@ -106,7 +165,7 @@ fn main() -> InquireResult<()> {
                 } else {
                     None
                 };
-                if let Err(e) = gr.push_edge(node1.clone(), node2.clone(), weight) {
+                if let Err(e) = history.push_edge(node1.clone(), node2.clone(), weight) {
                     safe_err!("Cannot connect node {node1:?} with {node2:?}: {e}");
                 }
             }
@@ -114,10 +173,20 @@ fn main() -> InquireResult<()> {
                 let nodes = gr.get_nodes();
                 let node1 = or_escape!(Select::new("Select first node:", nodes.clone()).prompt());
                 let node2 = or_escape!(Select::new("Select second node:", nodes).prompt());
-                if let Err(e) = gr.pop_edge(node1.clone(), node2.clone()) {
+                if let Err(e) = history.pop_edge(node1.clone(), node2.clone()) {
                     safe_err!("Cannot disconnect node {node1:?} with {node2:?}: {e}");
                 }
             }
+            "Undo" => match history.undo() {
+                Ok(Some(op)) => println!("Undone: {op}"),
+                Ok(None) => println!("Nothing to undo"),
+                Err(e) => safe_err!("Cannot undo: {e}"),
+            },
+            "Redo" => match history.redo() {
+                Ok(Some(op)) => println!("Redone: {op}"),
+                Ok(None) => println!("Nothing to redo"),
+                Err(e) => safe_err!("Cannot redo: {e}"),
+            },
             "Save to file" => {
                 let path = or_escape!(Text::new("Select graph file:")
                     .with_suggester(&suggest_file_paths)
@@ -128,181 +197,167 @@ fn main() -> InquireResult<()> {
                     safe_err!("Cannot save to {path:?}: {e}");
                 }
             }
-            "Tasks..." => {
-                // TODO: Convert closures into macros
-                let task3 = |f: &dyn Fn(&Graph) -> GraphResult<Vec<EdgeWeighted>>| match f(&gr) {
-                    Ok(mst) => {
-                        print!(
-                            "\n{}\n",
-                            mst.iter()
-                                .map(|t| format!("{t:?}"))
-                                .collect::<Vec<String>>()
-                                .join(", ")
-                        );
+            "Change properties..." => {
+                let options = vec![
+                    if gr.is_weighted() {
+                        "Make not weighted"
+                    } else {
+                        "Make weighted"
+                    },
+                    if gr.is_oriented() {
+                        "Make not oriented"
+                    } else {
+                        "Make oriented"
+                    },
+                ];
+                let res = match or_escape!(Select::new("What to change:", options).prompt()) {
+                    "Make weighted" => {
+                        let weight = or_escape!(CustomType::<EdgeWeight>::new(
+                            "Enter weight for every connection:"
+                        )
+                        .with_error_message("Enter an integer value")
+                        .prompt());
+                        Ok(gr.to_weighted(weight))
                     }
-                    Err(e) => safe_err!("Cannot find MST: {e}"),
-                };
-                let task1 = |f: &dyn Fn(&Graph, String, String) -> GraphResult<Vec<String>>,
-                             u,
-                             v| match f(&gr, u, v) {
-                    Ok(v) => print!(
-                        "\n{}\n",
-                        if !v.is_empty() {
-                            v.iter()
-                                .map(|t| format!("{t:?}"))
-                                .collect::<Vec<String>>()
-                                .join(", ")
+                    "Make not weighted" => Ok(gr.to_not_weighted()),
+                    "Make oriented" => Ok(gr.to_oriented()),
+                    "Make not oriented" => {
+                        let merge = if gr.is_weighted() {
+                            or_escape!(Select::new(
+                                "How to merge weights of opposite connections:",
+                                WeightMerge::ALL.to_vec(),
+                            )
+                            .prompt())
                         } else {
-                            "There is no such nodes!".into()
-                        }
-                    ),
-                    Err(e) => safe_err!("{e}"),
-                };
-                match or_escape!(Select::new("Select task:", tasks.clone()).prompt()) {
-                    TASK3KRUSKAL => task3(&algorithms::mst::kruskal),
-                    TASK3PRIM => task3(&algorithms::mst::prim),
-                    TASK1A1 => {
-                        let nodes = gr.get_nodes();
-                        let u = or_escape!(Select::new("Select node u:", nodes.clone()).prompt());
-                        let v = or_escape!(Select::new("Select node v:", nodes).prompt());
-                        task1(&tasks::task1::solve1a1, u, v);
-                    }
-                    TASK1A2 => {
-                        let nodes = gr.get_nodes();
-                        let u = or_escape!(Select::new("Select node u:", nodes.clone()).prompt());
-                        let v = or_escape!(Select::new("Select node v:", nodes).prompt());
-                        task1(&tasks::task1::solve1a2, u, v);
-                    }
-                    TASK1B => {
-                        match tasks::task1::solve1b(&gr) {
-                            Ok(new_gr) => gr = new_gr,
-                            Err(e) => safe_err!("{e}"),
+                            WeightMerge::Min
                         };
-                        print!("\nGraph has been inverted!\n")
+                        gr.to_not_oriented(merge)
                     }
-                    TASK21 => match tasks::task2::solve21(&gr) {
-                        Ok(c) => {
-                            print!("\n{:?}\n", c)
-                        }
-                        Err(e) => safe_err!("{e}"),
-                    },
-                    TASK22 => {
-                        let s = or_escape!(Select::new("From where:", gr.get_nodes()).prompt());
-                        let paths = tasks::task2::solve22(&gr, s);
-                        let mut paths = paths.iter().collect::<Vec<_>>();
-                        paths.sort_unstable();
-                        println!();
-                        for (to, path) in paths {
-                            print!("{to}: ");
-                            if path.is_empty() {
-                                println!("Unreachable");
-                            } else {
-                                println!("{path:?}");
-                            }
+                    _ => unreachable!(),
+                };
+                match res {
+                    Ok(new_gr) => history.replace(new_gr),
+                    Err(e) => safe_err!("Cannot change properties: {e}"),
+                }
+            }
+            "Switch to JSON output" | "Switch to text output" => json_output = !json_output,
+            "Command line..." => {
+                let new_gr = repl::run(gr.clone());
+                if new_gr != *gr {
+                    history.replace(new_gr);
+                }
+            }
+            "Animate algorithm..." => {
+                let algorithm =
+                    or_escape!(Select::new("Select algorithm:", Algorithm::ALL.to_vec()).prompt());
+                let start = if algorithm.needs_start() {
+                    Some(or_escape!(
+                        Select::new("Select node from:", gr.get_nodes()).prompt()
+                    ))
+                } else {
+                    None
+                };
+                match algorithm.trace(gr, start.as_deref()) {
+                    Ok(trace) => player::play(Animation::new(gr.clone(), trace))?,
+                    Err(e) => safe_err!("Cannot run {algorithm}: {e}"),
+                }
+            }
+            "Quiz..." => match exercise::run(None, None, &mut Random::from_time()) {
+                Ok(()) => (),
+                Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
+                    println!()
+                }
+                Err(e) => return Err(e),
+            },
+            "Graphs..." => {
+                let options = vec![
+                    "List graphs",
+                    "Switch active graph",
+                    "Add graph",
+                    "Duplicate active graph",
+                    "Remove graph",
+                ];
+                match or_escape!(Select::new("What to do with graphs:", options).prompt()) {
+                    "List graphs" => print!("\n{}\n", ws.describe().join("\n")),
+                    "Switch active graph" => {
+                        let name = or_escape!(Select::new("Select graph:", ws.names()).prompt());
+                        if let Err(e) = ws.switch(&name) {
+                            safe_err!("Cannot switch to {name:?}: {e}");
                         }
                     }
-                    TASK4A => {
-                        let nodes = gr.get_nodes();
-                        let res = tasks::task4::solve4a(&gr);
-                        if let Err(e) = &res {
-                            safe_err!("{e}\n");
-                            continue;
-                        }
-                        let res = res.unwrap();
-                        for start in &nodes {
-                            let paths = &res[start];
-                            for node in &nodes {
-                                if start == node {
-                                    continue;
-                                }
-                                let (weight, path) = &paths[node];
-                                match weight {
-                                    Some(w) => println!(
-                                        "{start} to {node}: {} weights {w}",
-                                        path.join(" -> ")
-                                    ),
-                                    None => println!("{start} to {node}: Unreachable"),
-                                }
-                            }
+                    "Add graph" => {
+                        let (name, gr) = or_escape!(prompt_graph());
+                        let name = or_escape!(prompt_name(&ws, &name));
+                        match ws.insert(name.clone(), gr) {
+                            Ok(()) => ws.switch(&name).unwrap(),
+                            Err(e) => safe_err!("Cannot add graph: {e}"),
                         }
                     }
-                    TASK4B => {
-                        let u = or_escape!(Select::new("Where to start:", gr.get_nodes()).prompt());
-                        let am = match algorithms::weighted::floyd(&gr) {
-                            Ok(am) => am,
-                            Err(e) => {
-                                safe_err!("{e}\n");
-                                continue;
-                            }
-                        };
-                        let from_u_weights = &am[&u];
-                        for (to, weight) in from_u_weights {
-                            if to == &u {
-                                continue;
-                            }
-                            match weight {
-                                Some(w) => println!("{u:?} to {to:?} weights {w}"),
-                                None => println!("Cannot get from {u:?} to {to:?}"),
-                            }
+                    "Duplicate active graph" => {
+                        let active = ws.active_name().to_string();
+                        let name = or_escape!(prompt_name(&ws, &format!("{active}-copy")));
+                        if let Err(e) = ws.duplicate(&active, name) {
+                            safe_err!("Cannot duplicate graph: {e}");
                         }
                     }
-                    TASK4C => {
-                        let mut v = match algorithms::weighted::find_negative_cycle(
-                            &gr,
-                            gr.get_nodes()[0].clone(),
-                        ) {
-                            Ok(v) => v,
-                            Err(e) => {
-                                safe_err!("{e}\n");
-                                continue;
-                            }
-                        };
-                        if v.is_empty() {
-                            println!("No negative cycle!")
-                        } else {
-                            v.reverse();
-                            println!("Found negative cycle: {}", v.join(" -> "));
+                    "Remove graph" => {
+                        let name = or_escape!(Select::new("Select graph:", ws.names()).prompt());
+                        if let Err(e) = ws.remove(&name) {
+                            safe_err!("Cannot remove graph: {e}");
                         }
                     }
-                    EDMONDS_KARP => {
-                        let s = or_escape!(Select::new("Pick source:", gr.get_nodes()).prompt());
-                        let t = or_escape!(Select::new("Pick sink:", gr.get_nodes()).prompt());
-                        match algorithms::weighted::edmonds_karp(&mut gr, s, t) {
-                            Ok(res) => println!("{}", res),
-                            Err(e) => {
-                                safe_err!("{e}\n");
-                                continue;
-                            }
-                        }
+                    _ => unreachable!(),
+                }
+            }
+            "Tasks..." => {
+                let descriptions = Task::ALL.iter().map(|t| t.description()).collect();
+                let description = or_escape!(Select::new("Select task:", descriptions).prompt());
+                let task = Task::ALL
+                    .into_iter()
+                    .find(|t| t.description() == description)
+                    .unwrap();
+                let args = task
+                    .args()
+                    .iter()
+                    .map(|arg| Select::new(&format!("Select node {arg}:"), gr.get_nodes()).prompt())
+                    .collect::<InquireResult<Vec<_>>>();
+                let args = or_escape!(args);
+
+                let output = match task.run(gr, &args) {
+                    Ok(output) => output,
+                    Err(e) => {
+                        safe_err!("{e}\n");
+                        continue;
                     }
-                    HELPER_DFS => {
-                        let s = or_escape!(Select::new("Where to start:", gr.get_nodes()).prompt());
-                        print!("\n{:?}\n", algorithms::traversals::dfs(&gr, s));
+                };
+                if json_output {
+                    print!("\n{}\n", output.to_json());
+                } else if task != Task::Invert {
+                    print!("\n{output}\n");
+                }
+
+                let result_gr = match output.to_graph(gr) {
+                    Ok(Some(result_gr)) => result_gr,
+                    Ok(None) => {
+                        println!();
+                        continue;
                     }
-                    HELPER_BFS => {
-                        let s = or_escape!(Select::new("Where to start:", gr.get_nodes()).prompt());
-                        print!("\n{:?}\n", algorithms::traversals::bfs(&gr, s));
+                    Err(e) => {
+                        safe_err!("Cannot build graph from the result: {e}\n");
+                        continue;
                     }
-                    HELPER_DIJKSTRA => {
-                        let nodes = gr.get_nodes();
-                        let start = or_escape!(Select::new("Start:", nodes.clone()).prompt());
-                        let res = algorithms::weighted::dijkstra_convenient(&gr, start.clone());
-                        if let Err(e) = &res {
-                            safe_err!("{e}\n");
-                            continue;
-                        }
-                        let paths = res.unwrap();
-                        for node in nodes {
-                            let (weight, path) = &paths[&node];
-                            match weight {
-                                Some(w) => {
-                                    println!("{}: {} weights {}", node, path.join(" -> "), w)
-                                }
-                                None => println!("{}: Unreachable from {}", node, start),
-                            }
-                        }
+                };
+                let save = task == Task::Invert
+                    || or_escape!(Confirm::new("Save result as a new graph?")
+                        .with_default(false)
+                        .prompt());
+                if save {
+                    let default = format!("{}-{}", ws.active_name(), task.name());
+                    let name = or_escape!(prompt_name(&ws, &default));
+                    match ws.insert(name.clone(), result_gr) {
+                        Ok(()) => println!("Result has been saved as {name:?}"),
+                        Err(e) => safe_err!("Cannot save result: {e}"),
                     }
-                    _ => safe_err!("Unknown algorithm"),
                 }
             }
             _ => safe_err!("Not implemented"),
@@ -310,45 +365,92 @@ fn main() -> InquireResult<()> {
         println!();
     }
 
+    if let Some(journal) = journal {
+        if let Err(e) = journal.finish() {
+            safe_err!("Cannot remove recovery journal: {e}");
+        }
+    }
+
     Ok(())
 }
 
+/// Ask user to create new graph or load it from file. Returns suggested name
+/// of the graph together with the graph itself.
+fn prompt_graph() -> Result<(String, Graph), Box<dyn std::error::Error>> {
+    let graph_creation_ans = Select::new(
+        "What do you want to start with?",
+        vec!["Create new graph", "Load from file"],
+    )
+    .prompt()?;
+
+    if graph_creation_ans == "Load from file" {
+        let file_path = Text::new("Select graph file:")
+            .with_suggester(&suggest_file_paths)
+            .with_completer(&complete_file_path)
+            .with_initial_value("./")
+            .prompt()?;
+        let name = std::path::Path::new(&file_path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "graph".to_string());
+        Ok((name, Graph::load(file_path)?))
+    } else {
+        let is_weighted_ans = Confirm::new("Do you want weighted graph?")
+            .with_default(false)
+            .prompt()?;
+        let is_oriented_ans = Confirm::new("Do you want oriented graph?")
+            .with_default(false)
+            .prompt()?;
+        Ok((
+            "graph".to_string(),
+            Graph::new(is_weighted_ans, is_oriented_ans),
+        ))
+    }
+}
+
+/// Ask user for a name of new graph in the workspace.
+fn prompt_name(ws: &Workspace, suggestion: &str) -> InquireResult<String> {
+    Text::new("Name of the new graph:")
+        .with_default(&ws.unique_name(suggestion))
+        .prompt()
+}
+
 fn suggest_file_paths(input: &str) -> Result<Vec<String>, CustomUserError> {
     Ok(list_paths(input)?)
 }
 
 fn complete_file_path(input: &str) -> Result<Option<String>, CustomUserError> {
-    // Implementation from https://rosettacode.org/wiki/Longest_common_prefix#Rust
-    fn longest_common_prefix<T: AsRef<[u8]>>(list: &[T]) -> Option<Vec<u8>> {
-        if list.is_empty() {
-            return None;
-        }
-        let mut ret = Vec::new();
-        let mut i = 0;
-        loop {
-            let mut c = None;
-            for word in list {
-                let word = word.as_ref();
-                if i == word.len() {
-                    return Some(ret);
-                }
-                match c {
-                    None => {
-                        c = Some(word[i]);
-                    }
-                    Some(letter) if letter != word[i] => return Some(ret),
-                    _ => continue,
-                }
+    Ok(longest_common_prefix(&list_paths(input)?)
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string()))
+}
+
+// Implementation from https://rosettacode.org/wiki/Longest_common_prefix#Rust
+fn longest_common_prefix<T: AsRef<[u8]>>(list: &[T]) -> Option<Vec<u8>> {
+    if list.is_empty() {
+        return None;
+    }
+    let mut ret = Vec::new();
+    let mut i = 0;
+    loop {
+        let mut c = None;
+        for word in list {
+            let word = word.as_ref();
+            if i == word.len() {
+                return Some(ret);
             }
-            if let Some(letter) = c {
-                ret.push(letter);
+            match c {
+                None => {
+                    c = Some(word[i]);
+                }
+                Some(letter) if letter != word[i] => return Some(ret),
+                _ => continue,
             }
-            i += 1;
         }
+        if let Some(letter) = c {
+            ret.push(letter);
+        }
+        i += 1;
     }
-
-    Ok(longest_common_prefix(&list_paths(input)?)
-        .map(|bytes| String::from_utf8_lossy(&bytes).to_string()))
 }
 
 fn list_paths(root: &str) -> std::io::Result<Vec<String>> {
@@ -0,0 +1,43 @@
+use graphs_at_ssu::animation::Animation;
+use inquire::{InquireError, Select};
+
+const NEXT: &str = "Next step";
+const PREVIOUS: &str = "Previous step";
+const FIRST: &str = "First step";
+const LAST: &str = "Last step";
+const EXIT: &str = "Exit";
+
+/// Show animation step by step, letting user go forward and backward
+/// until they exit.
+pub fn play(mut animation: Animation) -> Result<(), InquireError> {
+    let mut choice = NEXT;
+    loop {
+        print!("\x1B[2J\x1B[1;1H"); // clear the console
+        println!("{}\n", animation.render(true));
+
+        let options = vec![NEXT, PREVIOUS, FIRST, LAST, EXIT];
+        let cursor = options.iter().position(|o| *o == choice).unwrap();
+        choice = match Select::new("Where to go:", options)
+            .with_starting_cursor(cursor)
+            .prompt()
+        {
+            Ok(choice) => choice,
+            Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
+                return Ok(())
+            }
+            Err(e) => return Err(e),
+        };
+
+        match choice {
+            NEXT => {
+                animation.forward();
+            }
+            PREVIOUS => {
+                animation.back();
+            }
+            FIRST => animation.seek(0),
+            LAST => animation.seek(animation.len()),
+            _ => return Ok(()),
+        }
+    }
+}
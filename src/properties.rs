@@ -0,0 +1,105 @@
+use crate::*;
+
+/// How to combine weights of two opposite edges when making graph not
+/// oriented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightMerge {
+    Min,
+    Max,
+    Sum,
+}
+
+impl WeightMerge {
+    pub const ALL: [WeightMerge; 3] = [WeightMerge::Min, WeightMerge::Max, WeightMerge::Sum];
+
+    /// Find policy by its name.
+    pub fn from_name(name: &str) -> GraphResult<Self> {
+        WeightMerge::ALL
+            .into_iter()
+            .find(|m| m.to_string() == name)
+            .ok_or_else(|| GraphError {
+                msg: format!("Unknown weight merge policy {name:?}"),
+            })
+    }
+
+    fn merge(&self, a: EdgeWeight, b: EdgeWeight) -> GraphResult<EdgeWeight> {
+        match self {
+            WeightMerge::Min => Ok(a.min(b)),
+            WeightMerge::Max => Ok(a.max(b)),
+            WeightMerge::Sum => a.checked_add(b).ok_or_else(|| GraphError {
+                msg: format!("Sum of weights {a} and {b} overflows"),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for WeightMerge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WeightMerge::Min => write!(f, "min"),
+            WeightMerge::Max => write!(f, "max"),
+            WeightMerge::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+impl Graph {
+    /// Get oriented copy of the graph, where every edge of not oriented
+    /// graph goes both ways.
+    pub fn to_oriented(&self) -> Graph {
+        let mut gr = self.clone();
+        gr.is_oriented = true;
+        gr
+    }
+
+    /// Get not oriented copy of the graph. Edge going only one way is made
+    /// symmetric, weights of two opposite edges are combined with `merge`.
+    /// Not oriented graph is returned as is.
+    pub fn to_not_oriented(&self, merge: WeightMerge) -> GraphResult<Graph> {
+        if !self.is_oriented {
+            return Ok(self.clone());
+        }
+
+        let mut gr = self.clone();
+        gr.is_oriented = false;
+        for (from, to, weight) in self.get_edges() {
+            let opposite = self.adjacency_list[&to].get(&from);
+            let weight = match (weight, opposite) {
+                (Some(w1), Some(Some(w2))) if from != to => Some(merge.merge(w1, *w2)?),
+                _ => weight,
+            };
+            gr.adjacency_list
+                .get_mut(&from)
+                .unwrap()
+                .insert(to.clone(), weight);
+            gr.adjacency_list.get_mut(&to).unwrap().insert(from, weight);
+        }
+
+        Ok(gr)
+    }
+
+    /// Get weighted copy of the graph, where every edge has given weight.
+    pub fn to_weighted(&self, default: EdgeWeight) -> Graph {
+        self.map_weights(true, |_| Some(default))
+    }
+
+    /// Get not weighted copy of the graph, dropping all weights.
+    pub fn to_not_weighted(&self) -> Graph {
+        self.map_weights(false, |_| None)
+    }
+
+    fn map_weights(
+        &self,
+        is_weighted: bool,
+        f: impl Fn(Option<EdgeWeight>) -> Option<EdgeWeight>,
+    ) -> Graph {
+        let mut gr = self.clone();
+        gr.is_weighted = is_weighted;
+        for connections in gr.adjacency_list.values_mut() {
+            for weight in connections.values_mut() {
+                *weight = f(*weight);
+            }
+        }
+        gr
+    }
+}
@@ -0,0 +1,365 @@
+use crate::algorithms::{components, mst, weighted};
+use crate::*;
+
+use std::collections::BTreeSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Largest weight of edges in generated graphs.
+const MAX_WEIGHT: EdgeWeight = 20;
+
+/// Small pseudo-random generator (xorshift64*), good enough for exercises.
+#[derive(Debug, Clone)]
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        // Zero state would produce only zeros
+        Self(seed ^ 0x9e37_79b9_7f4a_7c15)
+    }
+
+    /// Seed generator with current time.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Get random number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn weight(&mut self) -> EdgeWeight {
+        1 + self.below(MAX_WEIGHT as usize) as EdgeWeight
+    }
+}
+
+/// Kinds of questions quiz can ask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestionKind {
+    MstWeight,
+    ShortestPath,
+    StronglyConnected,
+}
+
+impl QuestionKind {
+    pub const ALL: [QuestionKind; 3] = [
+        QuestionKind::MstWeight,
+        QuestionKind::ShortestPath,
+        QuestionKind::StronglyConnected,
+    ];
+
+    /// Short name used in the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            QuestionKind::MstWeight => "mst",
+            QuestionKind::ShortestPath => "path",
+            QuestionKind::StronglyConnected => "scc",
+        }
+    }
+
+    pub fn from_name(name: &str) -> GraphResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|k| k.name() == name)
+            .ok_or_else(|| GraphError {
+                msg: format!("Unknown kind of question {name:?}"),
+            })
+    }
+}
+
+impl fmt::Display for QuestionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuestionKind::MstWeight => write!(f, "Weight of minimum spanning tree"),
+            QuestionKind::ShortestPath => write!(f, "Shortest path between two nodes"),
+            QuestionKind::StronglyConnected => write!(f, "Strongly connected components"),
+        }
+    }
+}
+
+/// Result of checking an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub correct: bool,
+    /// Why the answer is wrong, or empty if it is correct.
+    pub reason: String,
+}
+
+impl Verdict {
+    pub(crate) fn correct() -> Self {
+        Self {
+            correct: true,
+            reason: String::new(),
+        }
+    }
+
+    pub(crate) fn wrong(reason: String) -> Self {
+        Self {
+            correct: false,
+            reason,
+        }
+    }
+}
+
+/// Random graph together with a question about it.
+#[derive(Debug, Clone)]
+pub struct Exercise {
+    pub graph: Graph,
+    pub kind: QuestionKind,
+    /// Nodes the question is about, e.g. ends of the path.
+    pub nodes: Vec<String>,
+}
+
+/// Get name of `i`-th node: `A`..`Z`, then `A1`, `B1` and so on.
+fn node_name(i: usize) -> String {
+    let letter = (b'A' + (i % 26) as u8) as char;
+    match i / 26 {
+        0 => letter.to_string(),
+        n => format!("{letter}{n}"),
+    }
+}
+
+impl Exercise {
+    /// Generate exercise on a random graph with given number of nodes.
+    pub fn generate(kind: QuestionKind, size: usize, rng: &mut Random) -> GraphResult<Self> {
+        if size < 2 {
+            return Err(GraphError {
+                msg: "Graph has to have at least 2 nodes".to_string(),
+            });
+        }
+
+        let mut gr = match kind {
+            QuestionKind::MstWeight => Graph::new(true, false),
+            QuestionKind::ShortestPath => Graph::new(true, true),
+            QuestionKind::StronglyConnected => Graph::new(false, true),
+        };
+        let nodes = (0..size).map(node_name).collect::<Vec<_>>();
+        for node in &nodes {
+            gr.push_node(node.to_string())?;
+        }
+        let is_weighted = gr.is_weighted();
+        let weight = |rng: &mut Random| is_weighted.then(|| rng.weight());
+
+        // Spanning tree keeps graph connected, so there is always an answer
+        if kind != QuestionKind::StronglyConnected {
+            for i in 1..size {
+                let j = rng.below(i);
+                let w = weight(rng);
+                gr.push_edge(nodes[j].to_string(), nodes[i].to_string(), w)?;
+            }
+        }
+        let extra = match kind {
+            QuestionKind::StronglyConnected => size * 3 / 2,
+            _ => size / 2 + 1,
+        };
+        for _ in 0..extra {
+            let (u, v) = (rng.below(size), rng.below(size));
+            if u != v {
+                let w = weight(rng);
+                gr.push_edge(nodes[u].to_string(), nodes[v].to_string(), w)?;
+            }
+        }
+
+        let nodes = match kind {
+            QuestionKind::ShortestPath => {
+                vec![
+                    nodes[0].to_string(),
+                    nodes[1 + rng.below(size - 1)].to_string(),
+                ]
+            }
+            _ => vec![],
+        };
+
+        Ok(Self {
+            graph: gr,
+            kind,
+            nodes,
+        })
+    }
+
+    /// Get text of the question together with the expected answer format.
+    pub fn question(&self) -> String {
+        match self.kind {
+            QuestionKind::MstWeight => {
+                "What is the total weight of a minimum spanning tree?".to_string()
+            }
+            QuestionKind::ShortestPath => format!(
+                "What is the shortest path from {} to {}? Write its nodes separated by spaces.",
+                self.nodes[0], self.nodes[1]
+            ),
+            QuestionKind::StronglyConnected => "What are the strongly connected components? \
+                 Write nodes of every component separated by spaces, and components \
+                 separated by `;`."
+                .to_string(),
+        }
+    }
+
+    /// Get the correct answer, as the library finds it.
+    pub fn solution(&self) -> GraphResult<String> {
+        Ok(match self.kind {
+            QuestionKind::MstWeight => {
+                let mst = mst::kruskal(&self.graph)?;
+                let edges = mst
+                    .iter()
+                    .map(|(u, v, w)| format!("{u} - {v} ({w})"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "{} (tree: {edges})",
+                    mst.iter().map(|e| e.2).sum::<EdgeWeight>()
+                )
+            }
+            QuestionKind::ShortestPath => {
+                let (distance, path) = self.shortest_path()?;
+                format!("{} (weight {distance})", path.join(" "))
+            }
+            QuestionKind::StronglyConnected => strongly_connected(&self.graph)
+                .iter()
+                .map(|c| c.iter().cloned().collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>()
+                .join("; "),
+        })
+    }
+
+    /// Get edges which form the correct answer, e.g. edges of the minimum
+    /// spanning tree.
+    pub fn solution_edges(&self) -> GraphResult<HashSet<(String, String)>> {
+        Ok(match self.kind {
+            QuestionKind::MstWeight => mst::kruskal(&self.graph)?
+                .into_iter()
+                .map(|(u, v, _)| (u, v))
+                .collect(),
+            QuestionKind::ShortestPath => formats::ascii::path_edges(&self.shortest_path()?.1),
+            QuestionKind::StronglyConnected => HashSet::new(),
+        })
+    }
+
+    /// Ends of the path the question is about.
+    fn ends(&self) -> GraphResult<(&String, &String)> {
+        match &self.nodes[..] {
+            [from, to] => Ok((from, to)),
+            _ => Err(GraphError {
+                msg: "Question about a path needs its two ends".to_string(),
+            }),
+        }
+    }
+
+    fn shortest_path(&self) -> GraphResult<(EdgeWeight, Vec<String>)> {
+        let (from, to) = self.ends()?;
+        let mut paths = weighted::dijkstra_convenient(&self.graph, from.to_string())?;
+        match paths.remove(to) {
+            Some((Some(distance), path)) => Ok((distance, path)),
+            Some((None, _)) => Err(GraphError {
+                msg: format!("{to} is unreachable from {from}"),
+            }),
+            None => Err(GraphError {
+                msg: format!("Node {to:?} does not exist"),
+            }),
+        }
+    }
+
+    /// Check the answer, accepting any optimal one.
+    pub fn check(&self, answer: &str) -> GraphResult<Verdict> {
+        Ok(match self.kind {
+            QuestionKind::MstWeight => {
+                let expected = mst::kruskal(&self.graph)?.iter().map(|e| e.2).sum();
+                match answer.trim().parse::<EdgeWeight>() {
+                    Ok(w) if w == expected => Verdict::correct(),
+                    Ok(w) if w < expected => {
+                        Verdict::wrong(format!("No spanning tree weighs as little as {w}"))
+                    }
+                    Ok(w) => Verdict::wrong(format!("There is a spanning tree lighter than {w}")),
+                    Err(_) => Verdict::wrong(format!("{:?} is not a number", answer.trim())),
+                }
+            }
+            QuestionKind::ShortestPath => {
+                // Arrows are separators only as a whole, so names like
+                // `New-York` stay intact
+                let path = answer
+                    .replace("->", " ")
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|n| !n.is_empty())
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>();
+                self.check_path(&path)?
+            }
+            QuestionKind::StronglyConnected => {
+                let given = answer
+                    .split(';')
+                    .map(|c| {
+                        c.split(|c: char| c.is_whitespace() || c == ',')
+                            .filter(|n| !n.is_empty())
+                            .map(|n| n.to_string())
+                            .collect::<BTreeSet<_>>()
+                    })
+                    .filter(|c| !c.is_empty())
+                    .collect::<BTreeSet<_>>();
+                let expected = strongly_connected(&self.graph);
+                if given == expected {
+                    Verdict::correct()
+                } else if let Some(c) = given.iter().find(|c| !expected.contains(*c)) {
+                    let nodes = c.iter().cloned().collect::<Vec<_>>().join(" ");
+                    Verdict::wrong(format!("{{{nodes}}} is not a strongly connected component"))
+                } else {
+                    Verdict::wrong("Some components are missing".to_string())
+                }
+            }
+        })
+    }
+
+    fn check_path(&self, path: &[String]) -> GraphResult<Verdict> {
+        let (distance, _) = self.shortest_path()?;
+        let (from, to) = self.ends()?;
+        if path.first() != Some(from) || path.last() != Some(to) {
+            return Ok(Verdict::wrong(format!(
+                "Path has to start at {from} and end at {to}"
+            )));
+        }
+
+        let al = self.graph.get_adjacency_list();
+        let mut weight: EdgeWeight = 0;
+        for w in path.windows(2) {
+            match al.get(&w[0]).and_then(|c| c.get(&w[1])) {
+                Some(edge) => {
+                    weight =
+                        edge.and_then(|e| weight.checked_add(e))
+                            .ok_or_else(|| GraphError {
+                                msg: "Path weight does not fit into a number".to_string(),
+                            })?;
+                }
+                None => {
+                    return Ok(Verdict::wrong(format!(
+                        "There is no edge from {} to {}",
+                        w[0], w[1]
+                    )))
+                }
+            }
+        }
+
+        Ok(if weight == distance {
+            Verdict::correct()
+        } else {
+            Verdict::wrong(format!(
+                "Path weighs {weight}, but there is one weighing {distance}"
+            ))
+        })
+    }
+}
+
+/// Strongly connected components as a set, so that their order does not
+/// matter.
+fn strongly_connected(gr: &Graph) -> BTreeSet<BTreeSet<String>> {
+    components::strongly_connected(gr)
+        .components
+        .into_iter()
+        .collect()
+}
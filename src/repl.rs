@@ -0,0 +1,115 @@
+use crate::{list_paths, longest_common_prefix};
+use graphs_at_ssu::script::{completions, Interpreter};
+use graphs_at_ssu::*;
+use inquire::{CustomUserError, Text};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// How many previous commands are kept between sessions.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Run command-line mode where commands of the script language are typed
+/// one by one, with completion of commands, tasks and node names. Returns
+/// graph as it is when user leaves.
+pub fn run(gr: Graph) -> Graph {
+    let mut interpreter = Interpreter::new(gr);
+    let mut history = load_history();
+
+    println!("Type `help` to list commands, `history` to see previous ones, `exit` to leave.");
+    loop {
+        let suggester = |input: &str| suggest(&interpreter.graph, &history, input);
+        let completer = |input: &str| -> Result<Option<String>, CustomUserError> {
+            let suggestions = suggest(&interpreter.graph, &[], input)?;
+            Ok(longest_common_prefix(&suggestions)
+                .map(|bytes| String::from_utf8_lossy(&bytes).to_string()))
+        };
+        let line = match Text::new(">")
+            .with_suggester(&suggester)
+            .with_completer(&completer)
+            .prompt()
+        {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let line = line.trim().to_string();
+        if line.is_empty() {
+            continue;
+        }
+        remember(&mut history, &line);
+
+        match line.as_str() {
+            "exit" | "quit" => break,
+            "history" => {
+                for (i, cmd) in history.iter().enumerate() {
+                    println!("{:>4}  {cmd}", i + 1);
+                }
+            }
+            _ => match interpreter.execute(&line) {
+                Ok(Some(output)) => println!("{output}"),
+                Ok(None) => {}
+                Err(e) => safe_err!("{e}"),
+            },
+        }
+    }
+
+    interpreter.graph
+}
+
+/// Suggest completions of the current word followed by previous commands
+/// starting with typed text.
+fn suggest(gr: &Graph, history: &[String], input: &str) -> Result<Vec<String>, CustomUserError> {
+    let mut suggestions = completions(gr, input);
+    if let Some(path) = input
+        .strip_prefix("load ")
+        .or_else(|| input.strip_prefix("save "))
+    {
+        let cmd = &input[..input.len() - path.len()];
+        suggestions.extend(list_paths(path)?.into_iter().map(|p| format!("{cmd}{p}")));
+    }
+    if !input.is_empty() {
+        for cmd in history.iter().rev() {
+            if cmd.starts_with(input) && !suggestions.contains(cmd) {
+                suggestions.push(cmd.to_string());
+            }
+        }
+    }
+
+    Ok(suggestions)
+}
+
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".graphs-at-ssu_history"))
+}
+
+fn load_history() -> Vec<String> {
+    history_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|s| s.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Add command to the history, saving it for the next sessions.
+fn remember(history: &mut Vec<String>, line: &str) {
+    if history.last().map(|l| l.as_str()) == Some(line) {
+        return;
+    }
+    history.push(line.to_string());
+
+    let Some(path) = history_path() else {
+        return;
+    };
+    let res = if history.len() > HISTORY_LIMIT {
+        history.drain(..history.len() - HISTORY_LIMIT);
+        std::fs::write(&path, history.join("\n") + "\n")
+    } else {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut f| writeln!(f, "{line}"))
+    };
+    if let Err(e) = res {
+        safe_err!("Cannot save history to {path:?}: {e}");
+    }
+}
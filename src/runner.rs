@@ -0,0 +1,326 @@
+use crate::algorithms::weighted::ShortestPaths;
+use crate::*;
+
+use std::collections::BTreeSet;
+
+/// Every task that can be run on a graph from the menu, a script or the
+/// command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Task {
+    AdjacentOnlyFromU,
+    AdjacentFromBoth,
+    Invert,
+    StronglyConnected,
+    UnweightedPaths,
+    ShortestPathDag,
+    Kruskal,
+    Prim,
+    AllPairs,
+    Floyd,
+    NegativeCycle,
+    MaxFlow,
+    Dfs,
+    Bfs,
+    Dijkstra,
+    Topological,
+}
+
+impl Task {
+    pub const ALL: [Task; 16] = [
+        Task::AdjacentOnlyFromU,
+        Task::AdjacentFromBoth,
+        Task::Invert,
+        Task::StronglyConnected,
+        Task::UnweightedPaths,
+        Task::ShortestPathDag,
+        Task::Kruskal,
+        Task::Prim,
+        Task::AllPairs,
+        Task::Floyd,
+        Task::NegativeCycle,
+        Task::MaxFlow,
+        Task::Dfs,
+        Task::Bfs,
+        Task::Dijkstra,
+        Task::Topological,
+    ];
+
+    /// Short name used to call task from scripts and command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Task::AdjacentOnlyFromU => "adjacent-only",
+            Task::AdjacentFromBoth => "adjacent-both",
+            Task::Invert => "invert",
+            Task::StronglyConnected => "scc",
+            Task::UnweightedPaths => "unweighted-paths",
+            Task::ShortestPathDag => "shortest-path-dag",
+            Task::Kruskal => "kruskal",
+            Task::Prim => "prim",
+            Task::AllPairs => "all-pairs",
+            Task::Floyd => "floyd",
+            Task::NegativeCycle => "negative-cycle",
+            Task::MaxFlow => "maxflow",
+            Task::Dfs => "dfs",
+            Task::Bfs => "bfs",
+            Task::Dijkstra => "dijkstra",
+            Task::Topological => "topological",
+        }
+    }
+
+    /// Human readable description shown in the menu.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Task::AdjacentOnlyFromU => {
+                "Ia. Find nodes which are adjacent from u, but aren't from v"
+            }
+            Task::AdjacentFromBoth => "Ia. Find nodes which are adjacent from u and v",
+            Task::Invert => "Ib. Inverse oriented graph",
+            Task::StronglyConnected => "II. Find strongly connected component in oriented graph",
+            Task::UnweightedPaths => "II. Find shortest path from given node to others",
+            Task::ShortestPathDag => "II. Find all shortest paths from given node as a graph",
+            Task::Kruskal => "III. Find MST using Kruskal algorithm",
+            Task::Prim => "III. Find MST using Prim algorithm",
+            Task::AllPairs => "IVa. Find shortest paths for every pair of nodes",
+            Task::Floyd => "IVb. Find shortest paths from u to others.",
+            Task::NegativeCycle => "IVc. Find negative cycle.",
+            Task::MaxFlow => "V. Find maximum flow using Edmonds–Karp algorithm",
+            Task::Dfs => "Get DFS from certain node",
+            Task::Bfs => "Get BFS from certain node",
+            Task::Dijkstra => "Find shortest paths from one node to others using Dijkstra",
+            Task::Topological => "Sort nodes in topological order",
+        }
+    }
+
+    /// Names of nodes task expects as arguments.
+    pub fn args(&self) -> &'static [&'static str] {
+        match self {
+            Task::AdjacentOnlyFromU | Task::AdjacentFromBoth => &["u", "v"],
+            Task::UnweightedPaths
+            | Task::ShortestPathDag
+            | Task::Floyd
+            | Task::Dfs
+            | Task::Bfs
+            | Task::Dijkstra => &["from"],
+            Task::MaxFlow => &["source", "sink"],
+            _ => &[],
+        }
+    }
+
+    /// Find task by its short name.
+    pub fn from_name(name: &str) -> GraphResult<Task> {
+        Task::ALL
+            .into_iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| GraphError {
+                msg: format!("Unknown task {name:?}"),
+            })
+    }
+
+    /// Run task on the graph with given node arguments.
+    pub fn run(&self, gr: &Graph, args: &[String]) -> GraphResult<TaskOutput> {
+        if args.len() != self.args().len() {
+            return Err(GraphError {
+                msg: format!(
+                    "Task {:?} expects {} argument(s): {}",
+                    self.name(),
+                    self.args().len(),
+                    self.args().join(", ")
+                ),
+            });
+        }
+        let al = gr.get_adjacency_list();
+        if let Some(node) = args.iter().find(|n| !al.contains_key(*n)) {
+            return Err(GraphError {
+                msg: format!("Node {node:?} does not exist"),
+            });
+        }
+        let arg = |i: usize| args[i].clone();
+
+        Ok(match self {
+            Task::AdjacentOnlyFromU => {
+                TaskOutput::Nodes(tasks::task1::solve1a1(gr, arg(0), arg(1))?)
+            }
+            Task::AdjacentFromBoth => {
+                TaskOutput::Nodes(tasks::task1::solve1a2(gr, arg(0), arg(1))?)
+            }
+            Task::Invert => TaskOutput::Graph(tasks::task1::solve1b(gr)?),
+            Task::StronglyConnected => TaskOutput::Components(tasks::task2::solve21(gr)?),
+            Task::UnweightedPaths => TaskOutput::UnweightedPaths(tasks::task2::solve22(gr, arg(0))),
+            Task::ShortestPathDag => TaskOutput::Graph(tasks::task2::solve22_all(gr, arg(0))),
+            Task::Kruskal => TaskOutput::Tree(algorithms::mst::kruskal(gr)?),
+            Task::Prim => TaskOutput::Tree(algorithms::mst::prim(gr)?),
+            Task::AllPairs => TaskOutput::AllPairs(tasks::task4::solve4a(gr)?),
+            Task::Floyd => {
+                let mut am = algorithms::weighted::floyd(gr)?;
+                TaskOutput::Distances(arg(0), am.remove(&arg(0)).unwrap())
+            }
+            Task::NegativeCycle => {
+                TaskOutput::Cycle(algorithms::weighted::find_any_negative_cycle(gr)?)
+            }
+            Task::MaxFlow => {
+                TaskOutput::Flow(algorithms::weighted::edmonds_karp(gr, arg(0), arg(1))?)
+            }
+            Task::Dfs => TaskOutput::Order(algorithms::traversals::dfs(gr, arg(0))),
+            Task::Bfs => TaskOutput::Order(algorithms::traversals::bfs(gr, arg(0))),
+            Task::Dijkstra => TaskOutput::Paths(
+                arg(0),
+                algorithms::weighted::dijkstra_convenient(gr, arg(0))?,
+            ),
+            Task::Topological => TaskOutput::Order(algorithms::traversals::topological_sort(gr)?),
+        })
+    }
+}
+
+/// Result of running a [`Task`].
+#[derive(Debug, Clone)]
+pub enum TaskOutput {
+    /// Set of nodes satisfying some property.
+    Nodes(Vec<String>),
+    /// Nodes in order of some traversal.
+    Order(Vec<String>),
+    /// Newly built graph.
+    Graph(Graph),
+    /// Strongly connected components.
+    Components(HashSet<BTreeSet<String>>),
+    /// Shortest in terms of number of edges path to every node.
+    UnweightedPaths(HashMap<String, Vec<String>>),
+    /// Edges of spanning tree.
+    Tree(Vec<EdgeWeighted>),
+    /// Shortest paths from given node to every other node.
+    Paths(String, ShortestPaths),
+    /// Shortest paths between every pair of nodes.
+    AllPairs(HashMap<String, ShortestPaths>),
+    /// Weights of shortest paths from given node to every other node.
+    Distances(String, HashMap<String, Option<EdgeWeight>>),
+    /// Negative cycle, empty if there is none.
+    Cycle(Vec<String>),
+    /// Value of maximum flow.
+    Flow(EdgeWeight),
+}
+
+impl TaskOutput {
+    /// Get result as a new graph if it can be represented as one: spanning
+    /// tree becomes not oriented graph with all nodes of `gr`, strongly
+    /// connected components become oriented graph where every node is a
+    /// component of `gr`.
+    pub fn to_graph(&self, gr: &Graph) -> GraphResult<Option<Graph>> {
+        Ok(match self {
+            TaskOutput::Graph(result) => Some(result.clone()),
+            TaskOutput::Tree(edges) => {
+                let mut tree = Graph::new(true, false);
+                for node in gr.get_nodes() {
+                    tree.push_node(node)?;
+                }
+                for (from, to, weight) in edges {
+                    tree.push_edge(from.to_string(), to.to_string(), Some(*weight))?;
+                }
+                Some(tree)
+            }
+            TaskOutput::Components(_) => Some(algorithms::components::condensation(gr)?),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for TaskOutput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TaskOutput::Nodes(nodes) if nodes.is_empty() => write!(f, "There is no such nodes!"),
+            TaskOutput::Nodes(nodes) => write!(
+                f,
+                "{}",
+                nodes
+                    .iter()
+                    .map(|n| format!("{n:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TaskOutput::Order(nodes) => write!(f, "{nodes:?}"),
+            TaskOutput::Graph(gr) => write!(f, "{}", gr.pretty_view()),
+            TaskOutput::Components(components) => {
+                let mut components = components.iter().collect::<Vec<_>>();
+                components.sort();
+                let lines = components
+                    .iter()
+                    .map(|c| format!("{c:?}"))
+                    .collect::<Vec<_>>();
+                write!(f, "{}", lines.join("\n"))
+            }
+            TaskOutput::UnweightedPaths(paths) => {
+                let mut paths = paths.iter().collect::<Vec<_>>();
+                paths.sort_unstable();
+                let lines = paths
+                    .iter()
+                    .map(|(to, path)| {
+                        if path.is_empty() {
+                            format!("{to}: Unreachable")
+                        } else {
+                            format!("{to}: {path:?}")
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", lines.join("\n"))
+            }
+            TaskOutput::Tree(edges) => write!(
+                f,
+                "{}",
+                edges
+                    .iter()
+                    .map(|t| format!("{t:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TaskOutput::Paths(start, paths) => {
+                let mut nodes = paths.keys().collect::<Vec<_>>();
+                nodes.sort();
+                let lines = nodes
+                    .iter()
+                    .map(|node| match &paths[*node] {
+                        (Some(w), path) => format!("{node}: {} weights {w}", path.join(" -> ")),
+                        (None, _) => format!("{node}: Unreachable from {start}"),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", lines.join("\n"))
+            }
+            TaskOutput::AllPairs(res) => {
+                let mut nodes = res.keys().collect::<Vec<_>>();
+                nodes.sort();
+                let mut lines = Vec::new();
+                for start in &nodes {
+                    for node in &nodes {
+                        if start == node {
+                            continue;
+                        }
+                        lines.push(match &res[*start][*node] {
+                            (Some(w), path) => {
+                                format!("{start} to {node}: {} weights {w}", path.join(" -> "))
+                            }
+                            (None, _) => format!("{start} to {node}: Unreachable"),
+                        });
+                    }
+                }
+                write!(f, "{}", lines.join("\n"))
+            }
+            TaskOutput::Distances(u, weights) => {
+                let mut weights = weights
+                    .iter()
+                    .filter(|(to, _)| *to != u)
+                    .collect::<Vec<_>>();
+                weights.sort();
+                let lines = weights
+                    .iter()
+                    .map(|(to, weight)| match weight {
+                        Some(w) => format!("{u:?} to {to:?} weights {w}"),
+                        None => format!("Cannot get from {u:?} to {to:?}"),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", lines.join("\n"))
+            }
+            TaskOutput::Cycle(cycle) if cycle.is_empty() => write!(f, "No negative cycle!"),
+            TaskOutput::Cycle(cycle) => {
+                write!(f, "Found negative cycle: {}", cycle.join(" -> "))
+            }
+            TaskOutput::Flow(flow) => write!(f, "{flow}"),
+        }
+    }
+}
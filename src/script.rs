@@ -0,0 +1,302 @@
+use crate::properties::WeightMerge;
+use crate::runner::{Task, TaskOutput};
+use crate::*;
+
+/// Help on commands understood by [`Interpreter`].
+pub const COMMANDS_HELP: &str = "\
+new DESCRIPTION        Replace graph with an empty one, e.g. `new not weighted oriented`
+load FILE              Replace graph with one loaded from file
+save FILE              Save graph to file
+show                   Print graph
+add-node NODE          Push new node
+remove-node NODE       Pop existing node
+connect U V [WEIGHT]   Connect two nodes
+disconnect U V         Disconnect two nodes
+make PROPERTY          Change graph properties: `oriented`,
+                       `not-oriented [min|max|sum]`, `weighted WEIGHT`,
+                       `not-weighted`
+run TASK [NODES...]    Run task on graph
+tasks                  List available tasks
+output json|text       Print results of tasks as JSON or as text
+help                   Show this message";
+
+/// Names of all commands understood by [`Interpreter`].
+pub const COMMANDS: [&str; 14] = [
+    "new",
+    "load",
+    "save",
+    "show",
+    "add-node",
+    "remove-node",
+    "connect",
+    "disconnect",
+    "make",
+    "run",
+    "tasks",
+    "output",
+    "help",
+    "#",
+];
+
+/// Error that happened on certain line of a script.
+#[derive(Debug, Clone)]
+pub struct ScriptError {
+    pub line: usize,
+    pub error: GraphError,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+/// Interpreter of line-oriented language for editing graph and running
+/// tasks on it, e.g.
+///
+/// ```text
+/// add-node X
+/// connect A X 5
+/// run kruskal
+/// save out.gr
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    pub graph: Graph,
+    /// Print results of tasks as JSON instead of text.
+    pub json_output: bool,
+}
+
+impl Interpreter {
+    pub fn new(graph: Graph) -> Self {
+        Self {
+            graph,
+            json_output: false,
+        }
+    }
+
+    /// Execute single command and return text it produces, if any.
+    pub fn execute(&mut self, line: &str) -> GraphResult<Option<String>> {
+        let words = split_words(line)?;
+        let (cmd, args) = match words.split_first() {
+            Some((cmd, _)) if cmd.starts_with('#') => return Ok(None),
+            Some((cmd, args)) => (cmd.as_str(), args),
+            None => return Ok(None),
+        };
+
+        match (cmd, args) {
+            ("new", _) => self.graph = Graph::from_description(&args.join(" "))?,
+            ("load", [path]) => self.graph = Graph::load(path.to_string())?,
+            ("save", [path]) => self.graph.save_to_file(path)?,
+            ("show", []) => return Ok(Some(self.graph.pretty_view())),
+            ("add-node", [node]) => {
+                self.graph.push_node(node.to_string())?;
+            }
+            ("remove-node", [node]) => {
+                self.graph.pop_node(node.to_string())?;
+            }
+            ("connect", [u, v]) => self.graph.push_edge(u.to_string(), v.to_string(), None)?,
+            ("connect", [u, v, weight]) => {
+                let weight = weight.parse::<EdgeWeight>()?;
+                self.graph
+                    .push_edge(u.to_string(), v.to_string(), Some(weight))?
+            }
+            ("disconnect", [u, v]) => {
+                self.graph.pop_edge(u.to_string(), v.to_string())?;
+            }
+            ("make", [property, rest @ ..]) => {
+                self.graph = match (property.as_str(), rest) {
+                    ("oriented", []) => self.graph.to_oriented(),
+                    ("not-oriented", []) => self.graph.to_not_oriented(WeightMerge::Min)?,
+                    ("not-oriented", [merge]) => {
+                        self.graph.to_not_oriented(WeightMerge::from_name(merge)?)?
+                    }
+                    ("weighted", [weight]) => self.graph.to_weighted(weight.parse()?),
+                    ("not-weighted", []) => self.graph.to_not_weighted(),
+                    _ => {
+                        return Err(GraphError {
+                            msg: format!("Cannot make graph {:?}", words[1..].join(" ")),
+                        })
+                    }
+                }
+            }
+            ("run", [task, nodes @ ..]) => {
+                return match Task::from_name(task)?.run(&self.graph, nodes)? {
+                    TaskOutput::Graph(gr) => {
+                        self.graph = gr;
+                        Ok(Some("Graph has been replaced with the result".to_string()))
+                    }
+                    output if self.json_output => Ok(Some(output.to_json().to_string())),
+                    output => Ok(Some(output.to_string())),
+                };
+            }
+            ("output", [format]) => match format.as_str() {
+                "json" => self.json_output = true,
+                "text" => self.json_output = false,
+                _ => {
+                    return Err(GraphError {
+                        msg: format!("Unknown output format {format:?}"),
+                    })
+                }
+            },
+            ("tasks", []) => return Ok(Some(tasks_help())),
+            ("help", []) => {
+                return Ok(Some(format!("{COMMANDS_HELP}\n\nTasks:\n{}", tasks_help())))
+            }
+            _ if COMMANDS.contains(&cmd) => {
+                return Err(GraphError {
+                    msg: format!("Wrong number of arguments for {cmd:?}"),
+                })
+            }
+            _ => {
+                return Err(GraphError {
+                    msg: format!("Unknown command {cmd:?}"),
+                })
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Execute every line of the script, writing produced text to `out`.
+    /// Returns errors of all failed lines; if `stop_on_error` is set,
+    /// execution stops after the first one.
+    pub fn run(
+        &mut self,
+        source: &str,
+        stop_on_error: bool,
+        out: &mut dyn Write,
+    ) -> Vec<ScriptError> {
+        let mut errors = Vec::new();
+        for (i, line) in source.lines().enumerate() {
+            let res = self.execute(line).and_then(|text| match text {
+                Some(text) => Ok(writeln!(out, "{text}")?),
+                None => Ok(()),
+            });
+            if let Err(error) = res {
+                errors.push(ScriptError { line: i + 1, error });
+                if stop_on_error {
+                    break;
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// Get list of tasks with their arguments and descriptions.
+fn tasks_help() -> String {
+    Task::ALL
+        .iter()
+        .map(|t| format!("{:<18} {}", usage(t), t.description()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Get task name together with its arguments.
+fn usage(task: &Task) -> String {
+    let mut usage = task.name().to_string();
+    for arg in task.args() {
+        usage.push_str(&format!(" {}", arg.to_uppercase()));
+    }
+    usage
+}
+
+/// Put word in double quotes if it would not be read back by [`split_words`]
+/// as a single word otherwise, escaping quotes and backslashes inside.
+pub fn quote(word: &str) -> String {
+    if word.is_empty() || word.contains(|c: char| c.is_whitespace() || c == '"') {
+        let escaped = word.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("\"{escaped}\"")
+    } else {
+        word.to_string()
+    }
+}
+
+/// Split line into whitespace separated words. Words containing spaces can
+/// be put in double quotes, inside of which `\"` and `\\` stand for a quote
+/// and a backslash.
+pub fn split_words(line: &str) -> GraphResult<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut in_quotes = false;
+
+    let mut chars = line.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_quotes && matches!(chars.peek(), Some('"' | '\\')) => {
+                word.get_or_insert_with(String::new)
+                    .push(chars.next().unwrap());
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                word.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if let Some(w) = word.take() {
+                    words.push(w);
+                }
+            }
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if in_quotes {
+        return Err(GraphError {
+            msg: "Unterminated quote".to_string(),
+        });
+    }
+    if let Some(w) = word {
+        words.push(w);
+    }
+
+    Ok(words)
+}
+
+/// Get possible completions of partially typed line: command names for the
+/// first word, task names after `run` and node names of the graph for other
+/// arguments. Every completion is the whole line with the last word
+/// completed.
+pub fn completions(gr: &Graph, line: &str) -> Vec<String> {
+    let (done, partial) = match line.rfind(char::is_whitespace) {
+        Some(i) => line.split_at(i + 1),
+        None => ("", line),
+    };
+    let words = split_words(done).unwrap_or_default();
+
+    let candidates: Vec<String> = match words.as_slice() {
+        [] => COMMANDS
+            .iter()
+            .filter(|c| **c != "#")
+            .map(|c| c.to_string())
+            .collect(),
+        [run] if run == "run" => Task::ALL.iter().map(|t| t.name().to_string()).collect(),
+        [new, ..] if new == "new" => vec!["not".into(), "weighted".into(), "oriented".into()],
+        [output] if output == "output" => vec!["json".into(), "text".into()],
+        [make] if make == "make" => ["oriented", "not-oriented", "weighted", "not-weighted"]
+            .map(|p| p.to_string())
+            .to_vec(),
+        [make, p] if make == "make" && p == "not-oriented" => {
+            WeightMerge::ALL.map(|m| m.to_string()).to_vec()
+        }
+        [make, ..] if make == "make" => vec![],
+        [cmd, ..] if cmd == "load" || cmd == "save" || cmd.starts_with('#') => vec![],
+        _ => gr
+            .get_nodes()
+            .into_iter()
+            .map(|n| {
+                if n.contains(char::is_whitespace) {
+                    format!("\"{n}\"")
+                } else {
+                    n
+                }
+            })
+            .collect(),
+    };
+
+    candidates
+        .into_iter()
+        .filter(|c| c.starts_with(partial) || c.starts_with(&format!("\"{partial}")))
+        .map(|c| format!("{done}{c}"))
+        .collect()
+}
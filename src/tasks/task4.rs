@@ -1,16 +1,6 @@
+use crate::algorithms::weighted::ShortestPaths;
 use crate::*;
 
-pub fn solve4a(
-    gr: &Graph,
-) -> GraphResult<HashMap<String, HashMap<String, (Option<u32>, Vec<String>)>>> {
-    let mut res = HashMap::new();
-    let nodes = gr.get_nodes();
-    for start in nodes {
-        res.insert(
-            start.clone(),
-            algorithms::weighted::dijkstra_convenient(gr, start)?,
-        );
-    }
-
-    Ok(res)
+pub fn solve4a(gr: &Graph) -> GraphResult<HashMap<String, ShortestPaths>> {
+    Ok(algorithms::weighted::johnson(gr)?)
 }
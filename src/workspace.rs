@@ -0,0 +1,139 @@
+use crate::history::History;
+use crate::*;
+
+use std::collections::BTreeMap;
+
+/// Set of named graphs with one of them being active. Every graph keeps its
+/// own history of changes.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    graphs: BTreeMap<String, History>,
+    active: String,
+}
+
+impl Workspace {
+    /// Create new workspace with the only graph, which is active.
+    pub fn new(name: String, gr: Graph) -> Self {
+        let mut graphs = BTreeMap::new();
+        graphs.insert(name.clone(), History::new(gr));
+        Self {
+            graphs,
+            active: name,
+        }
+    }
+
+    /// Get name of the active graph.
+    pub fn active_name(&self) -> &str {
+        &self.active
+    }
+
+    /// Get the active graph.
+    pub fn active(&self) -> &Graph {
+        self.graphs[&self.active].graph()
+    }
+
+    /// Get the active graph for modification, which is recorded in its
+    /// history.
+    pub fn active_mut(&mut self) -> &mut History {
+        self.graphs.get_mut(&self.active).unwrap()
+    }
+
+    /// Get graph by its name.
+    pub fn get(&self, name: &str) -> GraphResult<&Graph> {
+        self.history(name).map(|h| h.graph())
+    }
+
+    /// Get history of changes of graph by its name.
+    pub fn history(&self, name: &str) -> GraphResult<&History> {
+        self.graphs.get(name).ok_or_else(|| GraphError {
+            msg: format!("Graph {name:?} does not exist"),
+        })
+    }
+
+    /// Get names of all graphs in alphabetical order.
+    pub fn names(&self) -> Vec<String> {
+        self.graphs.keys().cloned().collect()
+    }
+
+    /// Add new graph to the workspace.
+    pub fn insert(&mut self, name: String, gr: Graph) -> GraphResult<()> {
+        if name.trim().is_empty() {
+            return Err(GraphError {
+                msg: "Graph name cannot be empty".to_string(),
+            });
+        }
+        if self.graphs.contains_key(&name) {
+            return Err(GraphError {
+                msg: format!("Graph {name:?} already exists"),
+            });
+        }
+        self.graphs.insert(name, History::new(gr));
+        Ok(())
+    }
+
+    /// Make graph with given name active.
+    pub fn switch(&mut self, name: &str) -> GraphResult<()> {
+        self.get(name)?;
+        self.active = name.to_string();
+        Ok(())
+    }
+
+    /// Add a copy of graph `name` to the workspace as `new_name`.
+    pub fn duplicate(&mut self, name: &str, new_name: String) -> GraphResult<()> {
+        let gr = self.get(name)?.clone();
+        self.insert(new_name, gr)
+    }
+
+    /// Remove graph from the workspace. Active graph cannot be removed.
+    pub fn remove(&mut self, name: &str) -> GraphResult<Graph> {
+        if name == self.active {
+            return Err(GraphError {
+                msg: format!("Graph {name:?} is active, switch to another one first"),
+            });
+        }
+        self.graphs
+            .remove(name)
+            .map(|h| h.graph().clone())
+            .ok_or_else(|| GraphError {
+                msg: format!("Graph {name:?} does not exist"),
+            })
+    }
+
+    /// Get name based on `base` which is not used yet by any graph.
+    pub fn unique_name(&self, base: &str) -> String {
+        let mut name = base.to_string();
+        let mut i = 2;
+        while self.graphs.contains_key(&name) {
+            name = format!("{base}-{i}");
+            i += 1;
+        }
+        name
+    }
+
+    /// Get one line summary of every graph, active one is marked with `*`.
+    pub fn describe(&self) -> Vec<String> {
+        self.graphs
+            .iter()
+            .map(|(name, history)| {
+                let gr = history.graph();
+                format!(
+                    "{} {name}: {}weighted {}oriented, {} nodes, {} edges",
+                    if *name == self.active { "*" } else { " " },
+                    if gr.is_weighted() { "" } else { "not " },
+                    if gr.is_oriented() { "" } else { "not " },
+                    gr.get_nodes().len(),
+                    edges_count(gr),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Count edges, treating two halves of not oriented edge as one.
+fn edges_count(gr: &Graph) -> usize {
+    let edges = gr.get_edges();
+    if gr.is_oriented() {
+        return edges.len();
+    }
+    edges.iter().filter(|(from, to, _)| from <= to).count()
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::trace::Event;
+    use graphs_at_ssu::animation::{Algorithm, Animation};
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_dijkstra_trace() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let mut trace = Vec::new();
+        let (dist, _) = algorithms::weighted::dijkstra_traced(&gr, "A".to_string(), &mut trace)?;
+        assert_eq!(
+            trace[0],
+            Event::Settled {
+                node: "A".to_string(),
+                distance: Some(0)
+            }
+        );
+        for event in &trace {
+            if let Event::Settled {
+                node,
+                distance: Some(d),
+            } = event
+            {
+                assert_eq!(dist[node], Some(*d));
+            }
+        }
+        assert!(trace.contains(&Event::Relaxed {
+            from: "D".to_string(),
+            to: "E".to_string(),
+            distance: 3
+        }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_kruskal_trace() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst1.gr".to_string())?;
+        let mut trace = Vec::new();
+        let mst = algorithms::mst::kruskal_traced(&gr, &mut trace)?;
+        let accepted = trace
+            .iter()
+            .filter(|e| matches!(e, Event::Accepted { .. }))
+            .count();
+        assert_eq!(accepted, mst.len());
+        // Every edge is considered once
+        assert_eq!(trace.len(), gr.get_edges().len() / 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs_trace() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal1.gr".to_string())?;
+        let mut trace = Vec::new();
+        let order = algorithms::traversals::dfs_traced(&gr, "A".to_string(), &mut trace);
+        let settled = trace
+            .iter()
+            .filter_map(|e| match e {
+                Event::Settled { node, .. } => Some(node.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(settled, order);
+        Ok(())
+    }
+
+    #[test]
+    fn test_animation_steps() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let trace = Algorithm::Prim.trace(&gr, None)?;
+        let mut animation = Animation::new(gr, trace);
+        assert!(!animation.back());
+        assert!(animation.state().tree.is_empty());
+
+        animation.seek(usize::MAX);
+        assert_eq!(animation.step(), animation.len());
+        assert!(!animation.forward());
+        let weight = animation
+            .state()
+            .tree
+            .iter()
+            .filter_map(|e| e.2)
+            .sum::<EdgeWeight>();
+        assert_eq!(weight, 30);
+        assert!(animation.render(false).contains("Tree weight: 30"));
+
+        assert!(animation.back());
+        assert_eq!(animation.step(), animation.len() - 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_animation_needs_start() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        assert!(Algorithm::Dfs.trace(&gr, None).is_err());
+        assert!(Algorithm::Dfs.trace(&gr, Some("Z")).is_err());
+        let animation = Animation::new(gr.clone(), Algorithm::Dijkstra.trace(&gr, Some("A"))?);
+        assert!(animation.render(false).contains("Distance"));
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::weighted::{self, BellmanFord};
+    use graphs_at_ssu::*;
+
+    fn graph(edges: &[(&str, &str, EdgeWeight)]) -> GraphResult<Graph> {
+        let mut gr = Graph::new(true, true);
+        for (from, to, weight) in edges {
+            for node in [from, to] {
+                if !gr.get_nodes().contains(&node.to_string()) {
+                    gr.push_node(node.to_string())?;
+                }
+            }
+            gr.push_edge(from.to_string(), to.to_string(), Some(*weight))?;
+        }
+        Ok(gr)
+    }
+
+    fn cycle_weight(gr: &Graph, cycle: &[String]) -> EdgeWeight {
+        let al = gr.get_adjacency_list();
+        let mut closed = cycle.to_vec();
+        closed.push(cycle[0].to_string());
+        closed
+            .windows(2)
+            .map(|w| al[&w[0]][&w[1]].expect("cycle has to follow edges"))
+            .sum()
+    }
+
+    #[test]
+    fn test_negative_edges() -> GraphResult<()> {
+        let gr = graph(&[("A", "B", 4), ("A", "C", 2), ("C", "B", -3), ("B", "D", 1)])?;
+        let BellmanFord::Paths((dist, prev)) = weighted::bellman_ford(&gr, "A".to_string())? else {
+            panic!("There is no negative cycle");
+        };
+        assert_eq!(dist["B"], Some(-1));
+        assert_eq!(dist["D"], Some(0));
+        assert_eq!(prev["B"], Some("C".to_string()));
+        assert_eq!(prev["A"], None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_as_dijkstra() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        for start in gr.get_nodes() {
+            let (expected, _) = weighted::dijkstra(&gr, start.to_string())?;
+            match weighted::bellman_ford(&gr, start)? {
+                BellmanFord::Paths((dist, _)) => assert_eq!(dist, expected),
+                cycle => panic!("Unexpected {cycle:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_cycle() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/negative_weight.gr".to_string())?;
+        let BellmanFord::NegativeCycle(cycle) = weighted::bellman_ford(&gr, "A".to_string())?
+        else {
+            panic!("Graph has negative cycles");
+        };
+        assert!(cycle_weight(&gr, &cycle) < 0);
+        assert_eq!(weighted::find_negative_cycle(&gr, "A".to_string())?, cycle);
+        assert!(cycle_weight(&gr, &weighted::find_any_negative_cycle(&gr)?) < 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreachable_cycle() -> GraphResult<()> {
+        let gr = graph(&[("A", "B", 1), ("D", "C", -2), ("C", "D", 1)])?;
+        assert!(weighted::find_negative_cycle(&gr, "A".to_string())?.is_empty());
+        assert!(matches!(
+            weighted::bellman_ford(&gr, "A".to_string())?,
+            BellmanFord::Paths(_)
+        ));
+        assert_eq!(weighted::find_any_negative_cycle(&gr)?, vec!["C", "D"]);
+
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        assert!(weighted::find_any_negative_cycle(&gr)?.is_empty());
+        assert!(weighted::bellman_ford(&gr, "Nowhere".to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_heavy_edges() -> GraphResult<()> {
+        // Going around the cycle soon gives distances less than any weight
+        let gr = graph(&[
+            ("A", "B", -1_000_000_000),
+            ("B", "C", -1_000_000_000),
+            ("C", "A", -1_000_000_000),
+        ])?;
+        let expected = vec!["A", "B", "C"];
+        assert_eq!(
+            weighted::find_negative_cycle(&gr, "A".to_string())?,
+            expected
+        );
+        assert_eq!(weighted::find_any_negative_cycle(&gr)?, expected);
+        let mut iterations = Vec::new();
+        weighted::find_negative_cycle_traced(&gr, "A".to_string(), &mut iterations)?;
+        assert_eq!(iterations[0].label, "initial");
+
+        // Without a cycle too big distance is still an error
+        let gr = graph(&[("A", "B", EdgeWeight::MIN), ("B", "C", -1)])?;
+        assert!(weighted::bellman_ford(&gr, "A".to_string()).is_err());
+        Ok(())
+    }
+}
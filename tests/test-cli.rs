@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use std::process::{Command, Output};
+
+    fn run(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_graphs-at-ssu"))
+            .args(args)
+            .output()
+            .expect("failed to run binary")
+    }
+
+    #[test]
+    fn test_cli_dijkstra() {
+        let out = run(&["dijkstra", "--from", "A", "graphs/abstract.gr"]);
+        assert_eq!(out.status.code(), Some(0));
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("E: A -> D -> E weights 3"));
+        assert!(stdout.contains("F: Unreachable from A"));
+    }
+
+    #[test]
+    fn test_cli_mst() {
+        let out = run(&["mst", "--algo", "prim", "graphs/tests/mst/mst2.gr"]);
+        assert_eq!(out.status.code(), Some(0));
+        assert!(String::from_utf8_lossy(&out.stdout).contains("Total weight: 30"));
+    }
+
+    #[test]
+    fn test_cli_maxflow() {
+        let out = run(&[
+            "maxflow",
+            "--source",
+            "a",
+            "--sink",
+            "f",
+            "graphs/tests/edmonds-karp.gr",
+        ]);
+        assert_eq!(out.status.code(), Some(0));
+        assert_eq!(String::from_utf8_lossy(&out.stdout).trim(), "11");
+    }
+
+    #[test]
+    fn test_cli_draw() {
+        let out = run(&[
+            "draw",
+            "--path",
+            "A,C",
+            "graphs/tests/traversals/traversal1.gr",
+        ]);
+        assert_eq!(out.status.code(), Some(0));
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("[A]") && stdout.contains("[K]"));
+        assert!(stdout.contains(['┃', '╋']) && !stdout.contains('\x1b'));
+
+        let out = run(&["draw", "--layout", "layered", "graphs/abstract.gr"]);
+        assert_eq!(out.status.code(), Some(1));
+    }
+
+    #[test]
+    fn test_cli_convert_formats() {
+        let out = run(&[
+            "convert",
+            "--format",
+            "mermaid",
+            "--mst",
+            "prim",
+            "graphs/tests/mst/mst2.gr",
+        ]);
+        assert_eq!(out.status.code(), Some(0));
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.starts_with("flowchart LR\n"));
+        assert_eq!(stdout.matches("===").count(), 8);
+
+        let out = run(&["convert", "--format", "svg", "--scc", "graphs/abstract.gr"]);
+        assert_eq!(out.status.code(), Some(0));
+        assert!(String::from_utf8_lossy(&out.stdout).starts_with("<svg"));
+
+        let out = run(&["convert", "--format", "dot", "graphs/abstract.gr"]);
+        assert_eq!(out.status.code(), Some(2));
+    }
+
+    #[test]
+    fn test_cli_quiz_size() {
+        for nodes in ["1", "27"] {
+            let out = run(&["quiz", "--nodes", nodes]);
+            assert_eq!(out.status.code(), Some(2));
+            assert!(String::from_utf8_lossy(&out.stderr).contains("from 2 to 26"));
+        }
+    }
+
+    #[test]
+    fn test_cli_json() {
+        let out = run(&["scc", "--json", "graphs/tests/task2/task21.gr"]);
+        assert_eq!(out.status.code(), Some(0));
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout).trim(),
+            r#"{"components":[["A","B","C"],["D","E","F"],["G","H"]]}"#
+        );
+
+        let out = run(&[
+            "run",
+            "maxflow",
+            "graphs/tests/edmonds-karp.gr",
+            "a",
+            "f",
+            "--json",
+        ]);
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout).trim(),
+            r#"{"max_flow":11}"#
+        );
+    }
+
+    #[test]
+    fn test_cli_grade() {
+        let answer = "target/test_cli_grade_answer.txt";
+        std::fs::write(answer, "Maximum flow: 11\n").unwrap();
+        let args = ["grade", "maxflow", "graphs/tests/edmonds-karp.gr", answer];
+        let out = run(&[&args[..], &["a", "f"]].concat());
+        assert_eq!(out.status.code(), Some(0));
+        assert_eq!(String::from_utf8_lossy(&out.stdout).trim(), "Correct");
+
+        std::fs::write(answer, "10").unwrap();
+        let out = run(&[&args[..], &["a", "f", "--json"]].concat());
+        assert_eq!(out.status.code(), Some(3));
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout).trim(),
+            r#"{"correct":false,"reason":"There is a flow greater than 10"}"#
+        );
+        assert_eq!(run(&args).status.code(), Some(1));
+        assert_eq!(
+            run(&["grade", "task9", "graphs/abstract.gr", answer])
+                .status
+                .code(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_cli_exit_codes() {
+        assert_eq!(
+            run(&["validate", "graphs/abstract.gr"]).status.code(),
+            Some(0)
+        );
+        assert_eq!(
+            run(&["scc", "graphs/travel_time.gr"]).status.code(),
+            Some(1)
+        );
+        assert_eq!(run(&["validate", "no/such/file.gr"]).status.code(), Some(1));
+        assert_eq!(
+            run(&["mst", "--algo", "foo", "graphs/travel_time.gr"])
+                .status
+                .code(),
+            Some(2)
+        );
+        assert_eq!(
+            run(&["dijkstra", "graphs/abstract.gr"]).status.code(),
+            Some(2)
+        );
+        assert_eq!(run(&["frobnicate"]).status.code(), Some(2));
+    }
+}
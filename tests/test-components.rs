@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use graphs_at_ssu::algorithms::components;
+    use graphs_at_ssu::*;
+
+    fn set(nodes: &[&str]) -> BTreeSet<String> {
+        nodes.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_topological_order() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task24.gr".to_string())?;
+        let found = components::strongly_connected(&gr);
+        assert_eq!(
+            found.components,
+            vec![
+                set(&["A"]),
+                set(&["B", "C"]),
+                set(&["D", "E", "F", "G"]),
+                set(&["H"]),
+                set(&["J"]),
+            ]
+        );
+        assert_eq!(found.component_of["E"], 2);
+        assert_eq!(found.component_of["C"], found.component_of["B"]);
+        assert_eq!(found.name(1), "{B C}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_mutually_reachable() -> GraphResult<()> {
+        // H is reachable from the big component, but it cannot reach it back
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let found = tasks::task2::solve21(&gr)?;
+        let expected = [
+            set(&["A", "B", "C", "D", "E"]),
+            set(&["F"]),
+            set(&["G"]),
+            set(&["H"]),
+            set(&["J"]),
+        ];
+        assert_eq!(found, expected.into_iter().collect());
+        Ok(())
+    }
+
+    #[test]
+    fn test_condensation() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task24.gr".to_string())?;
+        let condensed = components::condensation(&gr)?;
+        assert!(condensed.is_oriented());
+        assert_eq!(condensed.get_nodes().len(), 5);
+        let mut edges = condensed
+            .get_edges()
+            .into_iter()
+            .map(|(from, to, _)| format!("{from} -> {to}"))
+            .collect::<Vec<_>>();
+        edges.sort();
+        assert_eq!(
+            edges,
+            vec![
+                "{A} -> {B C}",
+                "{B C} -> {D E F G}",
+                "{D E F G} -> {H}",
+                "{H} -> {J}"
+            ]
+        );
+        algorithms::traversals::topological_sort(&condensed)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_oriented() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        assert_eq!(components::strongly_connected(&gr).components.len(), 1);
+        assert!(tasks::task2::solve21(&gr).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_cycle() -> GraphResult<()> {
+        let n = 30_000;
+        let mut gr = Graph::new(false, true);
+        for i in 0..n {
+            gr.push_node(i.to_string())?;
+        }
+        for i in 0..n {
+            gr.push_edge(i.to_string(), ((i + 1) % n).to_string(), None)?;
+        }
+        let found = components::strongly_connected(&gr);
+        assert_eq!(found.components.len(), 1);
+        assert_eq!(found.components[0].len(), n);
+
+        gr.pop_edge((n - 1).to_string(), "0".to_string())?;
+        let found = components::strongly_connected(&gr);
+        assert_eq!(found.components.len(), n);
+        assert_eq!(found.components[0], set(&["0"]));
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::weighted::{self, DijkstraError};
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_negative_edge() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/negative_weight.gr".to_string())?;
+        match weighted::dijkstra(&gr, "A".to_string()) {
+            Err(DijkstraError::NegativeEdge { from, to, weight }) => {
+                assert_eq!((from.as_str(), to.as_str(), weight), ("B", "D", -1));
+            }
+            res => panic!("Expected negative edge error, got {res:?}"),
+        }
+        assert!(weighted::dijkstra_convenient(&gr, "A".to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> GraphResult<()> {
+        let mut gr = Graph::new(true, true);
+        for node in ["A", "B", "C"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("A".to_string(), "B".to_string(), Some(EdgeWeight::MAX - 5))?;
+        gr.push_edge("B".to_string(), "C".to_string(), Some(10))?;
+        match weighted::dijkstra(&gr, "A".to_string()) {
+            Err(DijkstraError::Overflow { from, to }) => {
+                assert_eq!((from, to), ("B".into(), "C".into()))
+            }
+            res => panic!("Expected overflow error, got {res:?}"),
+        }
+        assert!(matches!(
+            weighted::dijkstra(&gr, "Z".to_string()),
+            Err(DijkstraError::Graph(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dijkstra_to() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let found = weighted::dijkstra_to(&gr, "A".to_string(), "E".to_string())?;
+        assert_eq!(
+            found,
+            Some((3, vec!["A".to_string(), "D".to_string(), "E".to_string()]))
+        );
+        assert_eq!(
+            weighted::dijkstra_to(&gr, "A".to_string(), "F".to_string())?,
+            None
+        );
+        assert!(weighted::dijkstra_to(&gr, "A".to_string(), "Z".to_string()).is_err());
+
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let found = weighted::dijkstra_to(&gr, "Home".to_string(), "Office".to_string())?;
+        assert_eq!(found.map(|f| f.0), Some(55));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dijkstra_multi() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let starts = ["Home".to_string(), "Office".to_string()];
+        let (dist, prev) = weighted::dijkstra_multi(&gr, &starts)?;
+        assert_eq!(dist["Home"], Some(0));
+        assert_eq!(dist["Office"], Some(0));
+        assert_eq!(dist["University"], Some(25));
+        assert_eq!(prev["University"], Some("Office".to_string()));
+        assert_eq!(dist["Shop"], Some(10));
+        assert_eq!(prev["Office"], None);
+        Ok(())
+    }
+}
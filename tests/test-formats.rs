@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use graphs_at_ssu::formats::json::Json;
+    use graphs_at_ssu::formats::svg::Overlay;
+    use graphs_at_ssu::layout::Layout;
+    use graphs_at_ssu::runner::Task;
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_cargo_lock1() -> GraphResult<()> {
+        let gr = Graph::from_cargo_lock("graphs/tests/lockfiles/simple.lock".to_string())?;
+        assert!(gr.is_oriented());
+        assert!(!gr.is_weighted());
+        assert_eq!(
+            gr.get_nodes(),
+            vec![
+                "app 0.1.0",
+                "log 0.4.17",
+                "rand 0.7.3",
+                "rand 0.8.5",
+                "serde 1.0.147"
+            ]
+        );
+
+        let edges = gr
+            .get_edges()
+            .into_iter()
+            .map(|(from, to, _)| (from, to))
+            .collect::<HashSet<_>>();
+        let expected = [
+            ("app 0.1.0", "log 0.4.17"),
+            ("app 0.1.0", "rand 0.8.5"),
+            ("app 0.1.0", "serde 1.0.147"),
+            ("log 0.4.17", "serde 1.0.147"),
+            ("rand 0.8.5", "rand 0.7.3"),
+        ]
+        .into_iter()
+        .map(|(from, to)| (from.to_string(), to.to_string()))
+        .collect::<HashSet<_>>();
+        assert_eq!(edges, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cargo_lock2() -> GraphResult<()> {
+        let gr = Graph::from_cargo_lock("graphs/tests/lockfiles/simple.lock".to_string())?;
+        let components = tasks::task2::solve21(&gr)?;
+        assert_eq!(components.len(), gr.get_nodes().len());
+        assert!(components.iter().all(|c: &BTreeSet<String>| c.len() == 1));
+
+        let dependents = tasks::task1::solve1b(&gr)?;
+        let al = dependents.get_adjacency_list();
+        let mut users_of_serde = al["serde 1.0.147"].keys().cloned().collect::<Vec<_>>();
+        users_of_serde.sort();
+        assert_eq!(users_of_serde, vec!["app 0.1.0", "log 0.4.17"]);
+
+        let order = algorithms::traversals::topological_sort(&gr)?;
+        assert_eq!(
+            order,
+            vec![
+                "app 0.1.0",
+                "log 0.4.17",
+                "rand 0.8.5",
+                "rand 0.7.3",
+                "serde 1.0.147"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cargo_lock3() {
+        let res =
+            Graph::from_cargo_lock("graphs/tests/lockfiles/unknown_dependency.lock".to_string());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_cargo_lock_sources() -> GraphResult<()> {
+        let gr = Graph::from_cargo_lock("graphs/tests/lockfiles/two_sources.lock".to_string())?;
+        let git = "log 0.4.17 (git+https://github.com/rust-lang/log#1234567)";
+        let registry = "log 0.4.17 (registry+https://github.com/rust-lang/crates.io-index)";
+        assert_eq!(
+            gr.get_nodes(),
+            vec!["app 0.1.0", git, registry, "serde 1.0.147"]
+        );
+
+        let al = gr.get_adjacency_list();
+        let mut deps = al["app 0.1.0"].keys().cloned().collect::<Vec<_>>();
+        deps.sort();
+        assert_eq!(deps, vec![git, registry, "serde 1.0.147"]);
+        assert!(al[registry].contains_key("serde 1.0.147"));
+        assert!(al[git].is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_escaping() {
+        let json = Json::object([
+            ("name", Json::from("say \"hi\"\n")),
+            ("weight", Json::from(Some(-5))),
+            ("missing", Json::from(None::<i32>)),
+            ("path", Json::strings(["A", "B"])),
+        ]);
+        assert_eq!(
+            json.to_string(),
+            r#"{"name":"say \"hi\"\n","weight":-5,"missing":null,"path":["A","B"]}"#
+        );
+    }
+
+    #[test]
+    fn test_json_mst() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let json = Task::Kruskal.run(&gr, &[])?.to_json();
+        match json {
+            Json::Object(pairs) => {
+                assert_eq!(pairs[0].0, "edges");
+                assert_eq!(pairs[1], ("total_weight".to_string(), Json::Number(30)));
+            }
+            _ => panic!("Expected object, got {json}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_dijkstra() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let json = Task::Dijkstra.run(&gr, &["A".to_string()])?.to_json();
+        let json = json.to_string();
+        assert!(json.contains(r#""distances":{"A":0,"B":1,"C":10,"D":2,"E":3,"F":null"#));
+        assert!(json.contains(r#""predecessors":{"A":null,"B":"A","C":"A","D":"A","E":"D""#));
+        assert!(json.contains(r#""E":["A","D","E"]"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tikz() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let mst = algorithms::mst::kruskal(&gr)?
+            .into_iter()
+            .map(|(u, v, _)| (u, v))
+            .collect::<HashSet<_>>();
+        let tikz = gr.to_tikz(&Layout::circular(&gr), &mst);
+        assert!(tikz.starts_with("\\begin{tikzpicture}"));
+        assert!(tikz.ends_with("\\end{tikzpicture}"));
+        assert!(tikz.contains("  \\node (n0) at (5.00, 7.00) {A};\n"));
+        assert!(tikz.contains(
+            "  \\draw[very thick, red] (n0) -- node[midway, draw=none, fill=white, font=\\small] {1} (n1);\n"
+        ));
+        assert_eq!(tikz.matches("very thick").count(), mst.len());
+        assert_eq!(tikz.matches("\\draw").count(), gr.get_edges().len() / 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tikz_oriented() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let tikz = gr.to_tikz(&Layout::auto(&gr), &HashSet::new());
+        assert!(tikz.contains("\\draw[->] (n0) to[loop above]"));
+        assert!(tikz.contains("\\draw[->] (n0) to[bend left=15]"));
+        assert_eq!(tikz.matches("\\draw").count(), gr.get_edges().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mermaid() -> GraphResult<()> {
+        let mut gr = Graph::new(true, false);
+        gr.push_node("A".to_string())?;
+        gr.push_node("say \"B\"".to_string())?;
+        gr.push_node("C".to_string())?;
+        gr.push_edge("A".to_string(), "say \"B\"".to_string(), Some(3))?;
+        gr.push_edge("C".to_string(), "A".to_string(), Some(1))?;
+        let emphasis = HashSet::from([("C".to_string(), "A".to_string())]);
+        assert_eq!(
+            gr.to_mermaid(&emphasis),
+            [
+                "flowchart LR",
+                "    n0[\"A\"]",
+                "    n1[\"C\"]",
+                "    n2[\"say #quot;B#quot;\"]",
+                "    n0 ===|1| n1",
+                "    n0 ---|3| n2",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_svg() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let components = tasks::task2::solve21(&gr)?;
+        let mut overlay = Overlay::components(&components);
+        overlay.edges = Overlay::path(&["A".to_string(), "D".to_string(), "E".to_string()]).edges;
+        let svg = gr.to_svg(&Layout::auto(&gr), &overlay);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<circle").count(), gr.get_nodes().len());
+        assert_eq!(svg.matches("<path d=").count(), gr.get_edges().len() + 2);
+        assert_eq!(
+            svg.matches("marker-end=\"url(#arrow-highlight)\"").count(),
+            2
+        );
+        // Nodes of the same component share color
+        let fill = |node: &str| {
+            let text = svg.find(&format!(">{node}</text>")).unwrap();
+            let circle = svg[..text].rfind("fill=\"").unwrap();
+            svg[circle..circle + 13].to_string()
+        };
+        assert_eq!(fill("A"), fill("E"));
+        assert_ne!(fill("A"), fill("G"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_svg_escaping() -> GraphResult<()> {
+        let mut gr = Graph::new(false, false);
+        gr.push_node("<A & B>".to_string())?;
+        let svg = gr.to_svg(&Layout::circular(&gr), &Overlay::default());
+        assert!(svg.contains(">&lt;A &amp; B&gt;</text>"));
+        assert!(!svg.contains("marker-end"));
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::grader::GradedTask;
+    use graphs_at_ssu::*;
+
+    fn grade(task: &str, file: &str, args: &[&str], answer: &str) -> GraphResult<(bool, String)> {
+        let gr = Graph::from_file(file.to_string())?;
+        let args = args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+        let verdict = GradedTask::from_name(task)?.grade(&gr, &args, answer)?;
+        Ok((verdict.correct, verdict.reason))
+    }
+
+    #[test]
+    fn test_grade_unweighted_paths() -> GraphResult<()> {
+        let file = "graphs/tests/task2/task22.gr";
+        let answer = "\
+G: Unreachable
+A: A
+B: A B
+C: A -> B -> C
+D: [\"A\", \"B\", \"C\", \"D\"]
+F: A, B, C, D, F
+E: A B C D E
+";
+        assert_eq!(
+            grade("task22", file, &["A"], answer)?,
+            (true, String::new())
+        );
+
+        let (correct, reason) = grade(
+            "task22",
+            file,
+            &["A"],
+            &answer.replace("A B C D E", "A B C D F E"),
+        )?;
+        assert!(!correct);
+        assert_eq!(reason, "There is no edge from F to E");
+        let (_, reason) = grade(
+            "task22",
+            file,
+            &["A"],
+            &answer.replace("G: Unreachable\n", ""),
+        )?;
+        assert_eq!(reason, "Path to G is missing");
+        let (_, reason) = grade(
+            "task22",
+            file,
+            &["A"],
+            &answer.replace("C: A -> B -> C", "C: Unreachable"),
+        )?;
+        assert_eq!(reason, "C is reachable from A");
+        assert!(grade("task22", file, &[], answer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grade_mst() -> GraphResult<()> {
+        let file = "graphs/tests/mst/mst2.gr";
+        let answer =
+            "J E 7\nA - B: 1\n(\"C\", \"B\", 2)\nD C\nD -> E 3\nE F\nE G 5\nE H\nTotal weight: 30";
+        assert_eq!(grade("mst", file, &[], answer)?, (true, String::new()));
+
+        let (_, reason) = grade("mst", file, &[], &answer.replace("J E 7", "J D 12"))?;
+        assert_eq!(reason, "Tree weighs 35, but there is one weighing 30");
+        let (_, reason) = grade("mst", file, &[], &answer.replace("E G 5", "E G 6"))?;
+        assert_eq!(reason, "Edge between E and G weighs 5, not 6");
+        let (_, reason) = grade("mst", file, &[], &answer.replace("E F", "A C"))?;
+        assert_eq!(reason, "Edge between A and C makes a cycle");
+        let (_, reason) = grade("mst", file, &[], &answer.replace("E H", ""))?;
+        assert_eq!(
+            reason,
+            "Tree does not span all nodes: it has 7 edge(s) instead of 8"
+        );
+        let (_, reason) = grade("mst", file, &[], "A G")?;
+        assert_eq!(reason, "There is no edge between A and G");
+        let (_, reason) = grade("mst", file, &[], "A B 1 C")?;
+        assert_eq!(
+            reason,
+            "Line \"A B 1 C\" is not an edge of two nodes and an optional weight"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_grade_mst_names() -> GraphResult<()> {
+        // Weights are told from nodes by position, so numbers work as names
+        let mut gr = Graph::new(true, false);
+        for node in ["1", "2", "3"] {
+            gr.push_node(node.to_string())?;
+        }
+        for (u, v, w) in [("1", "2", 3), ("1", "3", 1), ("2", "3", 1)] {
+            gr.push_edge(u.to_string(), v.to_string(), Some(w))?;
+        }
+        let mst = GradedTask::from_name("mst")?;
+        let answer = "1 - 3: 1\n3 - 2: 1\nTotal weight: 2";
+        assert!(mst.grade(&gr, &[], answer)?.correct);
+        assert!(mst.grade(&gr, &[], "3 1 1\n2 3 1")?.correct);
+        assert!(mst.grade(&gr, &[], "3 1\n2 3")?.correct);
+        let verdict = mst.grade(&gr, &[], "1 2 3\n2 3")?;
+        assert_eq!(verdict.reason, "Tree weighs 4, but there is one weighing 2");
+
+        // Names with spaces or dashes are quoted
+        let mut gr = Graph::new(true, false);
+        for node in ["New-York", "Los Angeles", "York"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("New-York".to_string(), "York".to_string(), Some(2))?;
+        gr.push_edge("York".to_string(), "Los Angeles".to_string(), Some(3))?;
+        let answer = "New-York - York: 2\n\"Los Angeles\" - York: 3";
+        assert!(mst.grade(&gr, &[], answer)?.correct);
+        let verdict = mst.grade(&gr, &[], "York \"Los Angeles")?;
+        assert_eq!(
+            verdict.reason,
+            "Line \"York \\\"Los Angeles\": Unterminated quote"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_grade_components() -> GraphResult<()> {
+        let file = "graphs/abstract.gr";
+        let answer = "{\"E\", \"D\", \"C\", \"B\", \"A\"}\nJ; H\nG\nF";
+        assert_eq!(grade("task21", file, &[], answer)?, (true, String::new()));
+
+        let (_, reason) = grade("scc", file, &[], &answer.replace("J; H", "J H"))?;
+        assert_eq!(reason, "H and J are not reachable from each other");
+        let (_, reason) = grade("scc", file, &[], &answer.replace("\"E\", ", "E;"))?;
+        assert_eq!(reason, "{E} is only a part of component {A B C D E}");
+        let (_, reason) = grade("scc", file, &[], &answer.replace("\nF", ""))?;
+        assert_eq!(reason, "Nodes F are missing");
+        assert!(grade("scc", "graphs/travel_time.gr", &[], answer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grade_flow() -> GraphResult<()> {
+        let file = "graphs/tests/edmonds-karp.gr";
+        assert!(grade("maxflow", file, &["a", "f"], "11")?.0);
+        let (_, reason) = grade("maxflow", file, &["a", "f"], "flow is 12")?;
+        assert_eq!(reason, "No flow is as great as 12");
+        let (_, reason) = grade("maxflow", file, &["a", "f"], "eleven")?;
+        assert_eq!(reason, "Answer has no number in it");
+        Ok(())
+    }
+}
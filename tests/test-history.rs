@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::history::History;
+    use graphs_at_ssu::properties::WeightMerge;
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_undo_pop_node() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let mut history = History::new(gr.clone());
+
+        history.pop_node("A".to_string())?;
+        assert!(!history.graph().get_nodes().contains(&"A".to_string()));
+
+        let op = history.undo()?.unwrap();
+        assert_eq!(op.to_string(), "pop node \"A\"");
+        assert_eq!(*history.graph(), gr);
+
+        history.redo()?;
+        assert!(!history.graph().get_nodes().contains(&"A".to_string()));
+        assert!(history.redo()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_edges() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let mut history = History::new(gr.clone());
+
+        history.push_edge("Cafe".to_string(), "Park".to_string(), Some(1))?;
+        history.pop_edge("Home".to_string(), "Shop".to_string())?;
+        history.push_node("Beach".to_string())?;
+        history.push_edge("Beach".to_string(), "Home".to_string(), Some(5))?;
+        let changed = history.graph().clone();
+
+        while history.undo()?.is_some() {}
+        assert_eq!(*history.graph(), gr);
+
+        while history.redo()?.is_some() {}
+        assert_eq!(*history.graph(), changed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_operation_clears_redo() -> GraphResult<()> {
+        let mut history = History::new(Graph::new(false, true));
+        history.push_node("A".to_string())?;
+        history.undo()?;
+        history.push_node("B".to_string())?;
+        assert!(history.redo()?.is_none());
+        assert_eq!(history.graph().get_nodes(), vec!["B"]);
+
+        history.replace(history.graph().to_not_oriented(WeightMerge::Min)?);
+        assert!(!history.graph().is_oriented());
+        history.undo()?;
+        assert!(history.graph().is_oriented());
+        Ok(())
+    }
+}
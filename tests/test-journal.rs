@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::journal::Journal;
+    use graphs_at_ssu::workspace::Workspace;
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_journal_recovery() -> GraphResult<()> {
+        let path =
+            &std::env::temp_dir().join(format!("graphs-at-ssu-journal-{}", std::process::id()));
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let mut ws = Workspace::new("travel".to_string(), gr);
+        let mut journal = Journal::start(path, &ws)?;
+
+        ws.active_mut().push_node("Old Town".to_string())?;
+        journal.sync(&ws)?;
+        ws.active_mut()
+            .push_edge("Old Town".to_string(), "Cafe".to_string(), Some(7))?;
+        journal.sync(&ws)?;
+        ws.active_mut().pop_node("Office".to_string())?;
+        ws.active_mut().undo()?;
+        ws.active_mut()
+            .pop_edge("Home".to_string(), "Shop".to_string())?;
+        journal.sync(&ws)?;
+
+        ws.duplicate("travel", "copy".to_string())?;
+        ws.insert(
+            "deps".to_string(),
+            Graph::from_cargo_lock("graphs/tests/lockfiles/simple.lock".to_string())?,
+        )?;
+        ws.switch("deps")?;
+        ws.active_mut().pop_node("log 0.4.17".to_string())?;
+        ws.remove("copy")?;
+        journal.sync(&ws)?;
+
+        // Journal of a running session is not touched by another one
+        assert!(Journal::start(path, &ws).is_err());
+        assert!(Journal::recover(path).is_err());
+        journal.sync(&ws)?;
+
+        drop(journal);
+        let recovered = Journal::recover(path)?.unwrap();
+        assert_eq!(recovered.names(), vec!["deps", "travel"]);
+        assert_eq!(recovered.active_name(), "deps");
+        assert_eq!(recovered.get("travel")?, ws.get("travel")?);
+        assert_eq!(recovered.get("deps")?, ws.get("deps")?);
+
+        let journal = Journal::start(path, &recovered)?;
+        journal.finish()?;
+        assert!(!path.exists());
+        assert!(Journal::recover(path)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_journal_broken() {
+        assert!(Journal::replay("@graph connect A B\n").is_err());
+        assert!(Journal::replay("# nothing here\n").is_err());
+        assert!(Journal::replay("graph new weighted oriented\n").is_err());
+    }
+}
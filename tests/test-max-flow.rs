@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::weighted::edmonds_karp;
+    use graphs_at_ssu::*;
+
+    fn graph(is_oriented: bool, edges: &[(&str, &str, EdgeWeight)]) -> GraphResult<Graph> {
+        let mut gr = Graph::new(true, is_oriented);
+        for (from, to, weight) in edges {
+            for node in [from, to] {
+                if !gr.get_nodes().contains(&node.to_string()) {
+                    gr.push_node(node.to_string())?;
+                }
+            }
+            gr.push_edge(from.to_string(), to.to_string(), Some(*weight))?;
+        }
+        Ok(gr)
+    }
+
+    fn max_flow(gr: &Graph, source: &str, sink: &str) -> GraphResult<i32> {
+        edmonds_karp(gr, source.to_string(), sink.to_string())
+    }
+
+    #[test]
+    fn test_max_flow() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/edmonds-karp.gr".to_string())?;
+        assert_eq!(max_flow(&gr, "a", "f")?, 11);
+        assert_eq!(max_flow(&gr, "b", "c")?, 5);
+        assert_eq!(max_flow(&gr, "f", "a")?, 0);
+
+        // Only edge to the sink limits the flow, however it is reached
+        let gr = graph(
+            true,
+            &[("s", "a", 3), ("s", "b", 5), ("a", "t", 5), ("b", "a", 5)],
+        )?;
+        assert_eq!(max_flow(&gr, "s", "t")?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_flow_back_edges() -> GraphResult<()> {
+        // The first shortest path s a b t blocks s c b t, so flow along a b
+        // has to be sent back to reach t through a d e
+        let gr = graph(
+            true,
+            &[
+                ("s", "a", 1),
+                ("s", "c", 1),
+                ("a", "b", 1),
+                ("c", "b", 1),
+                ("b", "t", 1),
+                ("a", "d", 1),
+                ("d", "e", 1),
+                ("e", "t", 1),
+            ],
+        )?;
+        assert_eq!(max_flow(&gr, "s", "t")?, 2);
+
+        let gr = graph(false, &[("s", "a", 4), ("a", "t", 3), ("t", "s", 2)])?;
+        assert_eq!(max_flow(&gr, "s", "t")?, 5);
+        assert_eq!(max_flow(&gr, "t", "s")?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_flow_errors() -> GraphResult<()> {
+        let gr = graph(true, &[("s", "t", i32::MAX), ("s", "a", 1), ("a", "t", 1)])?;
+        assert!(max_flow(&gr, "s", "t").is_err());
+        assert!(max_flow(&gr, "s", "s").is_err());
+        assert!(max_flow(&gr, "s", "x").is_err());
+        let gr = graph(true, &[("s", "t", -1)])?;
+        assert!(max_flow(&gr, "s", "t").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::paths::{simple_paths, PathLimits};
+    use graphs_at_ssu::*;
+
+    fn paths(gr: &Graph, from: &str, to: &str, limits: PathLimits) -> GraphResult<Vec<String>> {
+        Ok(simple_paths(gr, from, to, limits)?
+            .map(|(path, weight)| format!("{} ({weight})", path.join(" ")))
+            .collect())
+    }
+
+    #[test]
+    fn test_all_paths() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal2.gr".to_string())?;
+        assert_eq!(
+            paths(&gr, "A", "D", PathLimits::default())?,
+            vec!["A B C D (3)", "A C D (2)", "A D (1)"]
+        );
+        assert_eq!(paths(&gr, "A", "A", PathLimits::default())?, vec!["A (0)"]);
+        assert!(paths(&gr, "D", "A", PathLimits::default())?.is_empty());
+        assert!(simple_paths(&gr, "A", "Z", PathLimits::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_limits() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let limits = PathLimits {
+            max_edges: Some(2),
+            ..PathLimits::default()
+        };
+        assert_eq!(paths(&gr, "A", "C", limits)?, vec!["A B C (3)", "A C (10)"]);
+
+        let limits = PathLimits {
+            max_weight: Some(12),
+            ..PathLimits::default()
+        };
+        assert_eq!(
+            paths(&gr, "A", "D", limits)?,
+            vec!["A B C D (5)", "A B D (12)", "A C D (12)"]
+        );
+
+        let limits = PathLimits {
+            max_count: Some(4),
+            ..PathLimits::default()
+        };
+        let found = simple_paths(&gr, "A", "J", limits)?.collect::<Vec<_>>();
+        assert_eq!(found.len(), 4);
+        assert!(found
+            .iter()
+            .all(|(path, _)| path[0] == "A" && path.last().unwrap() == "J"));
+
+        let limits = PathLimits {
+            max_edges: Some(0),
+            ..PathLimits::default()
+        };
+        assert!(paths(&gr, "A", "B", limits)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy() -> GraphResult<()> {
+        // Complete graph has too many simple paths to find all of them
+        let n = 14;
+        let mut gr = Graph::new(false, true);
+        for i in 0..n {
+            gr.push_node(format!("{i:02}"))?;
+        }
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    gr.push_edge(format!("{i:02}"), format!("{j:02}"), None)?;
+                }
+            }
+        }
+
+        let mut found = simple_paths(&gr, "00", "13", PathLimits::default())?;
+        let (path, weight) = found.next().unwrap();
+        assert_eq!(path.len(), n);
+        assert_eq!(weight, n as EdgeWeight - 1);
+        assert_eq!(found.take(1000).count(), 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exhausted() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal2.gr".to_string())?;
+        for (from, to) in [("D", "A"), ("A", "D"), ("A", "A")] {
+            let mut iter = simple_paths(&gr, from, to, PathLimits::default())?;
+            while iter.next().is_some() {}
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> GraphResult<()> {
+        let mut gr = Graph::new(true, true);
+        for node in ["A", "B", "C"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("A".to_string(), "B".to_string(), Some(EdgeWeight::MAX))?;
+        gr.push_edge("B".to_string(), "C".to_string(), Some(1))?;
+        gr.push_edge("A".to_string(), "C".to_string(), Some(5))?;
+        assert_eq!(
+            paths(&gr, "A", "C", PathLimits::default())?,
+            vec!["A C (5)"]
+        );
+
+        // Only weight of the whole path has to fit, not weights of its parts
+        let max = EdgeWeight::MAX;
+        gr.push_node("D".to_string())?;
+        gr.push_edge("B".to_string(), "D".to_string(), Some(max))?;
+        gr.push_edge("D".to_string(), "C".to_string(), Some(EdgeWeight::MIN))?;
+        gr.push_edge("A".to_string(), "D".to_string(), Some(-10))?;
+        gr.pop_edge("B".to_string(), "C".to_string())?;
+        gr.push_edge("B".to_string(), "C".to_string(), Some(-10))?;
+        assert_eq!(
+            paths(&gr, "A", "C", PathLimits::default())?,
+            vec![
+                format!("A B C ({})", max - 10),
+                format!("A B D C ({})", max - 1),
+                "A C (5)".to_string(),
+            ]
+        );
+        let limits = PathLimits {
+            max_weight: Some(max - 5),
+            ..PathLimits::default()
+        };
+        assert_eq!(
+            paths(&gr, "A", "C", limits)?,
+            vec![format!("A B C ({})", max - 10), "A C (5)".to_string()]
+        );
+        Ok(())
+    }
+}
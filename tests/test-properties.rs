@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::properties::WeightMerge;
+    use graphs_at_ssu::*;
+
+    fn weight(gr: &Graph, from: &str, to: &str) -> Option<Option<EdgeWeight>> {
+        gr.get_adjacency_list()[from].get(to).cloned()
+    }
+
+    #[test]
+    fn test_to_not_oriented() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+
+        let min = gr.to_not_oriented(WeightMerge::Min)?;
+        assert!(!min.is_oriented());
+        assert_eq!(weight(&min, "A", "B"), Some(Some(1)));
+        assert_eq!(weight(&min, "B", "A"), Some(Some(1)));
+        assert_eq!(weight(&min, "J", "C"), Some(Some(10)));
+        assert_eq!(weight(&min, "C", "J"), Some(Some(10)));
+        assert_eq!(weight(&min, "A", "A"), Some(Some(8)));
+
+        let max = gr.to_not_oriented(WeightMerge::Max)?;
+        assert_eq!(weight(&max, "B", "A"), Some(Some(4)));
+
+        let sum = gr.to_not_oriented(WeightMerge::Sum)?;
+        assert_eq!(weight(&sum, "A", "B"), Some(Some(5)));
+        assert_eq!(weight(&sum, "A", "A"), Some(Some(8)));
+
+        // Result has to be a valid not oriented graph
+        for (from, to, w) in sum.get_edges() {
+            assert_eq!(weight(&sum, &to, &from), Some(w));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_not_oriented_twice() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        for merge in [WeightMerge::Min, WeightMerge::Max, WeightMerge::Sum] {
+            let once = gr.to_not_oriented(merge)?;
+            let twice = once.to_not_oriented(merge)?;
+            assert_eq!(twice.get_adjacency_list(), once.get_adjacency_list());
+        }
+
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let sum = gr.to_not_oriented(WeightMerge::Sum)?;
+        assert_eq!(sum.get_adjacency_list(), gr.get_adjacency_list());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_oriented() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let oriented = gr.to_oriented();
+        assert!(oriented.is_oriented());
+        assert_eq!(oriented.get_edges().len(), gr.get_edges().len());
+
+        let mut oriented = oriented;
+        oriented.pop_edge("Cafe".to_string(), "Park".to_string())?;
+        assert_eq!(weight(&oriented, "Park", "Cafe"), Some(Some(15)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_weights() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task21.gr".to_string())?;
+        let weighted = gr.to_weighted(3);
+        assert!(weighted.is_weighted());
+        assert!(weighted.get_edges().iter().all(|e| e.2 == Some(3)));
+
+        let unweighted = weighted.to_not_weighted();
+        assert!(!unweighted.is_weighted());
+        assert!(unweighted.get_edges().iter().all(|e| e.2.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_weight_merge_overflow() -> GraphResult<()> {
+        let mut gr = Graph::new(true, true);
+        gr.push_node("A".to_string())?;
+        gr.push_node("B".to_string())?;
+        gr.push_edge("A".to_string(), "B".to_string(), Some(EdgeWeight::MAX))?;
+        gr.push_edge("B".to_string(), "A".to_string(), Some(1))?;
+        assert!(gr.to_not_oriented(WeightMerge::Sum).is_err());
+        Ok(())
+    }
+}
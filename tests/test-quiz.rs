@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::quiz::{Exercise, QuestionKind, Random};
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_generate() -> GraphResult<()> {
+        for kind in QuestionKind::ALL {
+            for seed in 0..20 {
+                let exercise = Exercise::generate(kind, 7, &mut Random::new(seed))?;
+                assert_eq!(exercise.graph.get_nodes().len(), 7);
+                exercise.solution()?;
+            }
+        }
+        let a = Exercise::generate(QuestionKind::ShortestPath, 8, &mut Random::new(42))?;
+        let b = Exercise::generate(QuestionKind::ShortestPath, 8, &mut Random::new(42))?;
+        assert_eq!(a.graph, b.graph);
+        assert_eq!(a.nodes, b.nodes);
+        assert!(Exercise::generate(QuestionKind::MstWeight, 1, &mut Random::new(0)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mst_answer() -> GraphResult<()> {
+        let exercise = Exercise {
+            graph: Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?,
+            kind: QuestionKind::MstWeight,
+            nodes: vec![],
+        };
+        assert!(exercise.check(" 30 ")?.correct);
+        assert!(!exercise.check("29")?.correct);
+        assert!(!exercise.check("31")?.correct);
+        assert!(!exercise.check("thirty")?.correct);
+        assert!(exercise.solution()?.starts_with("30 (tree: "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_answer() -> GraphResult<()> {
+        let mut gr = Graph::new(true, true);
+        for node in ["A", "B", "C", "D"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("A".to_string(), "B".to_string(), Some(1))?;
+        gr.push_edge("B".to_string(), "D".to_string(), Some(2))?;
+        gr.push_edge("A".to_string(), "C".to_string(), Some(2))?;
+        gr.push_edge("C".to_string(), "D".to_string(), Some(1))?;
+        gr.push_edge("A".to_string(), "D".to_string(), Some(5))?;
+        let exercise = Exercise {
+            graph: gr,
+            kind: QuestionKind::ShortestPath,
+            nodes: vec!["A".to_string(), "D".to_string()],
+        };
+
+        // Both paths of weight 3 are fine
+        assert!(exercise.check("A B D")?.correct);
+        assert!(exercise.check("A -> C -> D")?.correct);
+        assert!(exercise.check("A->B, D")?.correct);
+        let verdict = exercise.check("A D")?;
+        assert!(!verdict.correct);
+        assert_eq!(verdict.reason, "Path weighs 5, but there is one weighing 3");
+        assert!(!exercise.check("A C B D")?.correct);
+        assert!(!exercise.check("B D")?.correct);
+        assert_eq!(exercise.solution_edges()?.len(), 2);
+
+        // Hand-made exercises the quiz would never generate are errors
+        let mut unreachable = exercise.clone();
+        unreachable.nodes.reverse();
+        assert!(unreachable.check("D A").is_err());
+        let mut unweighted = Graph::new(false, true);
+        for node in ["A", "B"] {
+            unweighted.push_node(node.to_string())?;
+        }
+        unweighted.push_edge("A".to_string(), "B".to_string(), None)?;
+        let unweighted = Exercise {
+            graph: unweighted,
+            ..exercise
+        };
+        assert!(unweighted.check("A B").is_err());
+        assert!(unweighted.solution().is_err());
+
+        // Hyphens are part of node names
+        let mut gr = Graph::new(true, false);
+        for node in ["New-York", "Los-Angeles", "York"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("New-York".to_string(), "York".to_string(), Some(1))?;
+        gr.push_edge("York".to_string(), "Los-Angeles".to_string(), Some(1))?;
+        let hyphens = Exercise {
+            graph: gr,
+            kind: QuestionKind::ShortestPath,
+            nodes: vec!["New-York".to_string(), "Los-Angeles".to_string()],
+        };
+        assert!(hyphens.check("New-York -> York -> Los-Angeles")?.correct);
+        assert!(hyphens.check("New-York York Los-Angeles")?.correct);
+        assert!(!hyphens.check("New York Los Angeles")?.correct);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scc_answer() -> GraphResult<()> {
+        let exercise = Exercise {
+            graph: Graph::from_file("graphs/abstract.gr".to_string())?,
+            kind: QuestionKind::StronglyConnected,
+            nodes: vec![],
+        };
+        assert_eq!(exercise.solution()?, "A B C D E; F; G; H; J");
+        assert!(exercise.check("F; J; G; H; E, D, C, B, A")?.correct);
+        let verdict = exercise.check("A B C D E H; F; G; J")?;
+        assert!(!verdict.correct);
+        assert_eq!(
+            verdict.reason,
+            "{A B C D E H} is not a strongly connected component"
+        );
+        assert!(!exercise.check("A B C D E; F; G")?.correct);
+        assert!(!exercise.check("A B D E; C; F; G; H; J")?.correct);
+        Ok(())
+    }
+}
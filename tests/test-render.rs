@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use graphs_at_ssu::formats::ascii::path_edges;
+    use graphs_at_ssu::layout::Layout;
+    use graphs_at_ssu::*;
+
+    fn chain(is_oriented: bool) -> GraphResult<Graph> {
+        let mut gr = Graph::new(true, is_oriented);
+        for node in ["A", "B", "C"] {
+            gr.push_node(node.to_string())?;
+        }
+        gr.push_edge("A".to_string(), "B".to_string(), Some(5))?;
+        gr.push_edge("B".to_string(), "C".to_string(), Some(7))?;
+        Ok(gr)
+    }
+
+    #[test]
+    fn test_layered_layout() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal1.gr".to_string())?;
+        let layout = Layout::layered(&gr)?;
+        for (u, v, _) in gr.get_edges() {
+            assert!(layout.positions[&u].1 < layout.positions[&v].1);
+        }
+        assert_eq!(layout, Layout::auto(&gr));
+        Ok(())
+    }
+
+    #[test]
+    fn test_layered_layout_with_cycle() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        assert!(Layout::layered(&gr).is_err());
+        assert_eq!(Layout::auto(&gr), Layout::force_directed(&gr));
+        for (x, y) in Layout::circular(&gr).positions.values() {
+            assert!((0.0..=1.0).contains(x) && (0.0..=1.0).contains(y));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_force_directed_layout() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/friendship.gr".to_string())?;
+        let layout = Layout::force_directed(&gr);
+        assert_eq!(layout, Layout::force_directed(&gr));
+        assert_eq!(layout, Layout::from_name("force", &gr)?);
+        assert!(Layout::from_name("spiral", &gr).is_err());
+
+        let points = layout.positions.values().collect::<Vec<_>>();
+        for (i, a) in points.iter().enumerate() {
+            assert!((0.0..=1.0).contains(&a.0) && (0.0..=1.0).contains(&a.1));
+            for b in &points[i + 1..] {
+                assert!((a.0 - b.0).hypot(a.1 - b.1) > 0.05);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ascii_oriented() -> GraphResult<()> {
+        let gr = chain(true)?;
+        let drawing = gr.to_ascii(&Layout::auto(&gr), &HashSet::new(), false);
+        assert_eq!(
+            drawing,
+            [
+                "     [A]",
+                "      │5",
+                "      ▼",
+                "     [B]",
+                "      │7",
+                "      ▼",
+                "     [C]",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ascii_highlight() -> GraphResult<()> {
+        let gr = chain(false)?;
+        let path = ["C".to_string(), "B".to_string()];
+        let drawing = gr.to_ascii(&Layout::circular(&gr), &path_edges(&path), false);
+        assert!(drawing.contains("[A]") && drawing.contains("[B]") && drawing.contains("[C]"));
+        assert!(drawing.contains('7') && drawing.contains('5'));
+        assert!(!drawing.contains('▼') && !drawing.contains('▲'));
+        assert!(drawing.contains(['━', '┃', '┏', '┓', '┗', '┛']));
+        assert!(drawing.contains(['─', '│', '┌', '┐', '└', '┘']));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ascii_weights() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let drawing = gr.to_ascii(&Layout::auto(&gr), &HashSet::new(), false);
+        for (_, _, w) in gr.get_edges() {
+            assert!(drawing.contains(&w.unwrap().to_string()));
+        }
+
+        // Horizontal parts of edges do not share rows, and weights which do not
+        // fit on their own edge are listed below
+        let mut gr = Graph::new(true, true);
+        for node in ["A", "B", "C"] {
+            gr.push_node(node.to_string())?;
+        }
+        for (u, v, w) in [
+            ("A", "B", 123456789),
+            ("A", "C", 2),
+            ("B", "A", 987654321),
+            ("B", "C", 5),
+            ("C", "A", 55555555),
+        ] {
+            gr.push_edge(u.to_string(), v.to_string(), Some(w))?;
+        }
+        let drawing = gr.to_ascii(&Layout::auto(&gr), &HashSet::new(), false);
+        let (picture, legend) = drawing.split_once("\n\nWeights:\n").unwrap();
+        assert_eq!(legend, "A→B: 123456789\nB→A: 987654321\nC→A: 55555555");
+        let rows = picture
+            .lines()
+            .filter(|line| line.contains(['─', '┌', '┐', '└', '┘']))
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 5);
+        for w in ["2", "5"] {
+            let row = rows.iter().find(|row| row.contains(w)).unwrap();
+            assert!(row.contains(&format!("─{w}")));
+        }
+
+        // Weight of a loop which runs into the next node is listed below
+        let mut gr = Graph::new(true, true);
+        let mut positions = HashMap::new();
+        for (i, node) in ["A", "B", "C", "D"].into_iter().enumerate() {
+            gr.push_node(node.to_string())?;
+            positions.insert(node.to_string(), (i as f64 / 3.0, 0.5));
+        }
+        gr.push_edge("A".to_string(), "A".to_string(), Some(i32::MAX))?;
+        let drawing = gr.to_ascii(&Layout { positions }, &HashSet::new(), false);
+        assert!(drawing.ends_with("\n\nWeights:\nA→A: 2147483647"));
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::script::*;
+    use graphs_at_ssu::*;
+
+    const TRIANGLE: &str = "\
+# build a triangle
+new weighted not oriented
+add-node A
+add-node B
+add-node \"C D\"
+connect A B 3
+connect B \"C D\" 4
+connect A \"C D\" 10
+connect A Z 1
+run kruskal
+";
+
+    #[test]
+    fn test_script_stop_on_error() {
+        let mut interpreter = Interpreter::new(Graph::new(false, false));
+        let mut out = Vec::new();
+        let errors = interpreter.run(TRIANGLE, true, &mut out);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 9);
+        assert!(out.is_empty());
+        assert_eq!(interpreter.graph.get_nodes(), vec!["A", "B", "C D"]);
+    }
+
+    #[test]
+    fn test_script_keep_going() {
+        let mut interpreter = Interpreter::new(Graph::new(false, false));
+        let mut out = Vec::new();
+        let errors = interpreter.run(TRIANGLE, false, &mut out);
+
+        assert_eq!(errors.len(), 1);
+        let out = String::from_utf8_lossy(&out);
+        assert_eq!(out.lines().count(), 1);
+        assert!(out.contains(", 3)") && out.contains(", 4)") && !out.contains("10"));
+    }
+
+    #[test]
+    fn test_script_tasks() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task21.gr".to_string())?;
+        let mut interpreter = Interpreter::new(gr);
+
+        assert!(interpreter.execute("run invert")?.is_some());
+        assert!(interpreter.graph.get_adjacency_list()["B"].contains_key("A"));
+        assert!(interpreter.execute("remove-node A")?.is_none());
+        assert!(interpreter.execute("run dfs A").is_err());
+        assert!(interpreter.execute("run bfs").is_err());
+        assert!(interpreter.execute("frobnicate").is_err());
+
+        interpreter.execute("make weighted 2")?;
+        interpreter.execute("make not-oriented sum")?;
+        assert!(interpreter.graph.is_weighted() && !interpreter.graph.is_oriented());
+        assert!(interpreter.execute("make not-oriented avg").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_words() -> GraphResult<()> {
+        assert_eq!(
+            split_words("connect \"serde 1.0.147\"  log 5")?,
+            vec!["connect", "serde 1.0.147", "log", "5"]
+        );
+        assert!(split_words("add-node \"unterminated").is_err());
+        assert_eq!(split_words("C:\\graphs")?, vec!["C:\\graphs"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quote_round_trip() -> GraphResult<()> {
+        let words = [
+            "plain",
+            "two words",
+            "say \"hi\"",
+            "back\\slash \\\"",
+            "\"",
+            "",
+        ];
+        let line = words.map(quote).join(" ");
+        assert_eq!(split_words(&line)?, words);
+        Ok(())
+    }
+
+    #[test]
+    fn test_help() -> GraphResult<()> {
+        let mut interpreter = Interpreter::new(Graph::new(false, false));
+        let help = interpreter.execute("help")?.unwrap();
+        for task in runner::Task::ALL {
+            assert!(help.contains(task.description()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_completions() -> GraphResult<()> {
+        let gr = Graph::from_cargo_lock("graphs/tests/lockfiles/simple.lock".to_string())?;
+        assert_eq!(completions(&gr, "dis"), vec!["disconnect"]);
+        assert_eq!(completions(&gr, "run kr"), vec!["run kruskal"]);
+        assert_eq!(
+            completions(&gr, "connect r"),
+            vec!["connect \"rand 0.7.3\"", "connect \"rand 0.8.5\""]
+        );
+        assert_eq!(
+            completions(&gr, "output "),
+            vec!["output json", "output text"]
+        );
+        assert!(completions(&gr, "save ").is_empty());
+        Ok(())
+    }
+}
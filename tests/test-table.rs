@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::formats::table::{self, latex_escape};
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_dijkstra_table() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let table = table::dijkstra_table(&gr, "A".to_string())?;
+        assert_eq!(table.header[..3], ["Iteration", "A", "B"]);
+        assert_eq!(table.rows[0][..3], ["initial", "0", "∞"]);
+        assert_eq!(table.rows[1][..5], ["settle A", "0", "1", "10", "2"]);
+
+        let last = table.rows.last().unwrap();
+        let dist = algorithms::weighted::dijkstra(&gr, "A".to_string())?.0;
+        for (node, cell) in table.header[1..].iter().zip(&last[1..]) {
+            assert_eq!(dist[node].map_or("∞".to_string(), |d| d.to_string()), *cell);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_floyd_tables() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let tables = table::floyd_tables(&gr)?;
+        assert_eq!(tables.len(), gr.get_nodes().len() + 1);
+
+        let floyd = algorithms::weighted::floyd(&gr)?;
+        let last = tables.last().unwrap();
+        assert_eq!(last.title.as_deref(), Some("k = University"));
+        for row in &last.rows {
+            for (to, cell) in last.header[1..].iter().zip(&row[1..]) {
+                assert_eq!(floyd[&row[0]][to].unwrap().to_string(), *cell);
+            }
+        }
+
+        let markdown = tables[0].to_markdown();
+        assert!(markdown.starts_with("**initial**\n\n|  | Cafe | Home |"));
+        assert!(markdown.contains("\n|---|---|---|---|---|---|---|---|\n"));
+        assert!(markdown.contains("\n| Cafe | ∞ | 50 | 80 | 15 | 40 | 50 | 35 |\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_cycle_table() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/negative_weight.gr".to_string())?;
+        let table = table::negative_cycle_table(&gr, "A".to_string())?;
+        assert_eq!(table.rows[0][0], "initial");
+        assert_eq!(table.rows[1][0], "round 1");
+        assert_eq!(table.rows.len(), gr.get_nodes().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_latex() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/abstract.gr".to_string())?;
+        let latex = table::dijkstra_table(&gr, "A".to_string())?.to_latex();
+        assert!(latex.starts_with("\\begin{tabular}{l|ccccccccc}\n"));
+        assert!(latex.contains("  initial & 0 & $\\infty$"));
+        assert!(latex.ends_with("\\end{tabular}"));
+        assert_eq!(latex_escape("a_b & 50%"), "a\\_b \\& 50\\%");
+        Ok(())
+    }
+}
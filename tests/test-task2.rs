@@ -308,4 +308,62 @@ mod tests {
         assert_eq!(components, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_shortest_paths_grid() -> GraphResult<()> {
+        // Grid has too many simple paths to look at every one of them
+        let n = 12;
+        let name = |i: usize, j: usize| format!("{i}-{j}");
+        let mut gr = Graph::new(false, false);
+        for i in 0..n {
+            for j in 0..n {
+                gr.push_node(name(i, j))?;
+            }
+        }
+        for i in 0..n {
+            for j in 0..n {
+                if i + 1 < n {
+                    gr.push_edge(name(i, j), name(i + 1, j), None)?;
+                }
+                if j + 1 < n {
+                    gr.push_edge(name(i, j), name(i, j + 1), None)?;
+                }
+            }
+        }
+
+        let paths = tasks::task2::solve22(&gr, name(0, 0));
+        assert_eq!(paths[&name(n - 1, n - 1)].len(), 2 * n - 1);
+        assert_eq!(paths[&name(0, 0)], vec![name(0, 0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_paths_dag() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let dag = tasks::task2::solve22_all(&gr, "A".to_string());
+        assert!(dag.is_oriented());
+        assert_eq!(dag.get_nodes(), gr.get_nodes());
+        let al = dag.get_adjacency_list();
+        let into = |node: &str| {
+            let mut from = al
+                .iter()
+                .filter(|(_, to)| to.contains_key(node))
+                .map(|(from, _)| from.as_str())
+                .collect::<Vec<_>>();
+            from.sort();
+            from
+        };
+        assert!(into("A").is_empty());
+        assert_eq!(into("D"), vec!["B", "C"]);
+        assert_eq!(into("J"), vec!["D", "E"]);
+        assert_eq!(into("E"), vec!["F"]);
+        assert_eq!(al["A"]["B"], Some(1));
+        assert_eq!(dag.get_edges().len(), 10);
+        algorithms::traversals::topological_sort(&dag)?;
+
+        let gr = Graph::from_file("graphs/tests/task2/task22.gr".to_string())?;
+        let dag = tasks::task2::solve22_all(&gr, "A".to_string());
+        assert!(!dag.get_nodes().contains(&"G".to_string()));
+        Ok(())
+    }
 }
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::algorithms::weighted::{self, BellmanFord, JohnsonError};
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_negative_edges() -> GraphResult<()> {
+        let mut gr = Graph::new(true, true);
+        for node in ["A", "B", "C", "D", "E"] {
+            gr.push_node(node.to_string())?;
+        }
+        for (from, to, weight) in [
+            ("A", "B", 4),
+            ("A", "C", 2),
+            ("C", "B", -3),
+            ("B", "D", 1),
+            ("D", "A", 2),
+            ("E", "D", -7),
+        ] {
+            gr.push_edge(from.to_string(), to.to_string(), Some(weight))?;
+        }
+
+        let res = tasks::task4::solve4a(&gr)?;
+        assert_eq!(
+            res["A"]["D"],
+            (
+                Some(0),
+                vec!["A", "C", "B", "D"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            )
+        );
+        assert_eq!(
+            res["E"]["B"],
+            (
+                Some(-6),
+                vec!["E", "D", "A", "C", "B"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            )
+        );
+        assert_eq!(res["A"]["E"].0, None);
+        for start in gr.get_nodes() {
+            let BellmanFord::Paths((dist, _)) = weighted::bellman_ford(&gr, start.to_string())?
+            else {
+                panic!("There is no negative cycle");
+            };
+            for (node, d) in dist {
+                assert_eq!(res[&start][&node].0, d);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_as_dijkstra() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let res = weighted::johnson(&gr)?;
+        for start in gr.get_nodes() {
+            let expected = weighted::dijkstra_convenient(&gr, start.to_string())?;
+            for (node, (d, path)) in expected {
+                assert_eq!(res[&start][&node].0, d);
+                assert_eq!(res[&start][&node].1.len(), path.len());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_cycle() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/negative_weight.gr".to_string())?;
+        match weighted::johnson(&gr) {
+            Err(JohnsonError::NegativeCycle(cycle)) => {
+                assert_eq!(cycle, weighted::find_any_negative_cycle(&gr)?);
+            }
+            res => panic!("Expected negative cycle, got {res:?}"),
+        }
+        let err = tasks::task4::solve4a(&gr).unwrap_err();
+        assert!(err.msg.contains("negative cycle"));
+        Ok(())
+    }
+}
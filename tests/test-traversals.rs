@@ -1,6 +1,8 @@
 #[cfg(test)]
 
 mod tests {
+    use std::collections::HashMap;
+
     use graphs_at_ssu::*;
 
     #[test]
@@ -31,7 +33,7 @@ mod tests {
     fn test_bfs1() -> GraphResult<()> {
         let gr = Graph::from_file("graphs/tests/traversals/traversal1.gr".to_string())?;
         let bfs = algorithms::traversals::bfs(&gr, "A".to_string());
-        assert_eq!(bfs, vec!["E", "F", "B", "G", "H", "C", "J", "K", "D", "A"]);
+        assert_eq!(bfs, vec!["A", "B", "C", "D", "E", "F", "G", "H", "J", "K"]);
         Ok(())
     }
     
@@ -39,7 +41,7 @@ mod tests {
     fn test_bfs2() -> GraphResult<()> {
         let gr = Graph::from_file("graphs/tests/traversals/traversal2.gr".to_string())?;
         let bfs = algorithms::traversals::bfs(&gr, "A".to_string());
-        assert_eq!(bfs, vec!["J", "K", "D", "C", "E", "F", "B", "A"]);
+        assert_eq!(bfs, vec!["A", "B", "C", "D", "E", "F", "J", "K"]);
         Ok(())
     }
     
@@ -47,7 +49,196 @@ mod tests {
     fn test_bfs3() -> GraphResult<()> {
         let gr = Graph::from_file("graphs/tests/traversals/traversal3.gr".to_string())?;
         let bfs = algorithms::traversals::bfs(&gr, "A".to_string());
-        assert_eq!(bfs, vec!["K", "D", "J", "H", "C", "G", "F", "E", "B", "A"]);
+        assert_eq!(bfs, vec!["A", "B", "C", "D", "E", "F", "G", "H", "J", "K"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfs_levels() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal3.gr".to_string())?;
+        let result = algorithms::traversals::breadth_first(&gr, "E".to_string());
+        assert_eq!(
+            result.order,
+            vec!["E", "B", "F", "A", "G", "C", "D", "H", "J", "K"]
+        );
+        assert_eq!(result.levels["E"], 0);
+        assert_eq!(result.levels["G"], 2);
+        assert_eq!(result.levels["K"], 4);
+        assert_eq!(result.parents["H"], "G");
+        assert!(!result.parents.contains_key("E"));
+        assert_eq!(result.path_to("J"), vec!["E", "B", "A", "D", "J"]);
+
+        assert!(!result.tree.is_oriented());
+        assert_eq!(result.tree.get_nodes(), gr.get_nodes());
+        assert_eq!(
+            result.tree.get_edges().len(),
+            2 * (gr.get_nodes().len() - 1)
+        );
+        assert_eq!(result.tree.get_adjacency_list()["A"].len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfs_to() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task24.gr".to_string())?;
+        let mut parent = HashMap::new();
+        let path =
+            algorithms::traversals::bfs_to(&gr, "E".to_string(), "J".to_string(), &mut parent);
+        assert_eq!(path, vec!["E", "F", "H", "J"]);
+        assert_eq!(parent["D"], "G");
+
+        let path =
+            algorithms::traversals::bfs_to(&gr, "E".to_string(), "A".to_string(), &mut parent);
+        assert!(path.is_empty());
+        let result = algorithms::traversals::breadth_first(&gr, "E".to_string());
+        assert!(!result.levels.contains_key("A"));
+        assert!(result.tree.is_oriented());
+        assert_eq!(result.tree.get_nodes(), vec!["D", "E", "F", "G", "H", "J"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_traversals() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task24.gr".to_string())?;
+        let mut dfs = algorithms::traversals::Dfs::new(&gr, "D");
+        let first = dfs.by_ref().take(3).collect::<Vec<_>>();
+        assert_eq!(first, vec!["D", "E", "F"]);
+        assert_eq!(dfs.parent().map(|p| p.as_str()), Some("E"));
+        assert!(!dfs.is_visited("G"));
+        assert!(dfs.restart("A"));
+        assert!(!dfs.restart("E"));
+        assert!(!dfs.restart("Z"));
+        let rest = dfs.collect::<Vec<_>>();
+        assert_eq!(rest, vec!["G", "H", "J", "A", "B", "C"]);
+
+        let mut bfs = algorithms::traversals::Bfs::new(&gr, "F");
+        assert_eq!(bfs.next(), Some("F".to_string()));
+        assert_eq!(bfs.parent(), None);
+        assert_eq!(bfs.next(), Some("G".to_string()));
+        assert_eq!(bfs.parent().map(|p| p.as_str()), Some("F"));
+        assert!(bfs.is_seen("H"));
+        assert!(bfs.restart("B"));
+        let rest = bfs.collect::<Vec<_>>();
+        assert_eq!(rest, vec!["H", "D", "J", "E", "B", "C"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_forest() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task23.gr".to_string())?;
+        let dfs = algorithms::traversals::Dfs::forest(&gr).collect::<Vec<_>>();
+        assert_eq!(dfs.len(), gr.get_nodes().len());
+        let bfs = algorithms::traversals::Bfs::forest(&gr).collect::<Vec<_>>();
+        assert_eq!(bfs.len(), gr.get_nodes().len());
+        assert_eq!(algorithms::traversals::Dfs::new(&gr, "Z").next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deep_chain() -> GraphResult<()> {
+        let n = 200_000;
+        let mut gr = Graph::new(false, true);
+        for i in 0..n {
+            gr.push_node(format!("{i:06}"))?;
+        }
+        for i in 1..n {
+            gr.push_edge(format!("{:06}", i - 1), format!("{i:06}"), None)?;
+        }
+
+        let dfs = algorithms::traversals::dfs(&gr, "000000".to_string());
+        assert_eq!(dfs.len(), n);
+        assert_eq!(dfs.last().unwrap(), "199999");
+        let bfs = algorithms::traversals::Bfs::new(&gr, "000000");
+        assert_eq!(bfs.last().unwrap(), "199999");
+        assert_eq!(algorithms::traversals::Dfs::forest(&gr).count(), n);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs_visit_oriented() -> GraphResult<()> {
+        use algorithms::traversals::EdgeKind::*;
+
+        let gr = Graph::from_file("graphs/tests/task2/task24.gr".to_string())?;
+        let mut record = algorithms::traversals::DfsRecord::default();
+        algorithms::traversals::dfs_visit(&gr, Some("C"), &mut record);
+        let edges = record
+            .edges
+            .iter()
+            .map(|(from, to, kind)| (from.as_str(), to.as_str(), *kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            edges,
+            vec![
+                ("C", "B", Tree),
+                ("B", "C", Back),
+                ("C", "D", Tree),
+                ("D", "E", Tree),
+                ("E", "F", Tree),
+                ("F", "G", Tree),
+                ("G", "D", Back),
+                ("F", "H", Tree),
+                ("H", "J", Tree),
+                ("D", "F", Forward),
+            ]
+        );
+        assert_eq!(record.discovered["C"], 1);
+        assert_eq!(record.finished["C"], 16);
+        assert_eq!(record.discovered["J"], 10);
+        assert_eq!(record.finished["J"], 11);
+        assert!(!record.discovered.contains_key("A"));
+
+        let mut gr = Graph::new(false, true);
+        for node in ["A", "B", "C"] {
+            gr.push_node(node.to_string())?;
+        }
+        for (from, to) in [("A", "B"), ("A", "C"), ("C", "B"), ("C", "C")] {
+            gr.push_edge(from.to_string(), to.to_string(), None)?;
+        }
+        let mut record = algorithms::traversals::DfsRecord::default();
+        algorithms::traversals::dfs_visit(&gr, None, &mut record);
+        let kinds = record.edges.iter().map(|e| e.2).collect::<Vec<_>>();
+        assert_eq!(kinds, vec![Tree, Tree, Cross, Back]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs_visit_not_oriented() -> GraphResult<()> {
+        use algorithms::traversals::EdgeKind;
+
+        let gr = Graph::from_file("graphs/tests/traversals/traversal3.gr".to_string())?;
+        let mut record = algorithms::traversals::DfsRecord::default();
+        algorithms::traversals::dfs_visit(&gr, None, &mut record);
+
+        let nodes = gr.get_nodes().len();
+        let count = |kind| record.edges.iter().filter(|e| e.2 == kind).count();
+        assert_eq!(record.edges.len(), gr.get_edges().len() / 2);
+        assert_eq!(count(EdgeKind::Tree), nodes - 1);
+        assert_eq!(count(EdgeKind::Forward) + count(EdgeKind::Cross), 0);
+        for (from, to, kind) in &record.edges {
+            // Back edges go to ancestors, tree edges to children
+            let (from, to) = match kind {
+                EdgeKind::Back => (to, from),
+                _ => (from, to),
+            };
+            assert!(record.discovered[from] < record.discovered[to]);
+            assert!(record.finished[to] < record.finished[from]);
+        }
+        assert_eq!(record.finished["A"], 2 * nodes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_topological_sort1() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/traversals/traversal1.gr".to_string())?;
+        let order = algorithms::traversals::topological_sort(&gr)?;
+        assert_eq!(order, vec!["A", "B", "C", "D", "E", "F", "G", "H", "J", "K"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_topological_sort2() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/task2/task21.gr".to_string())?;
+        assert!(algorithms::traversals::topological_sort(&gr).is_err());
         Ok(())
     }
 }
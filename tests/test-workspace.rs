@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use graphs_at_ssu::runner::Task;
+    use graphs_at_ssu::workspace::Workspace;
+    use graphs_at_ssu::*;
+
+    #[test]
+    fn test_workspace() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/travel_time.gr".to_string())?;
+        let mut ws = Workspace::new("travel".to_string(), gr);
+        assert_eq!(ws.active_name(), "travel");
+
+        ws.duplicate("travel", "backup".to_string())?;
+        ws.active_mut().pop_node("Cafe".to_string())?;
+        assert_eq!(ws.active().get_nodes().len(), 6);
+        assert_eq!(ws.get("backup")?.get_nodes().len(), 7);
+
+        assert!(ws
+            .insert("backup".to_string(), Graph::new(false, false))
+            .is_err());
+        assert_eq!(ws.unique_name("backup"), "backup-2");
+
+        ws.switch("backup")?;
+        assert!(ws.switch("missing").is_err());
+        assert!(ws.remove("backup").is_err());
+        ws.remove("travel")?;
+        assert_eq!(ws.names(), vec!["backup"]);
+        assert_eq!(
+            ws.describe(),
+            vec!["* backup: weighted not oriented, 7 nodes, 21 edges"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_results_as_graphs() -> GraphResult<()> {
+        let gr = Graph::from_file("graphs/tests/mst/mst2.gr".to_string())?;
+        let mst = Task::Kruskal.run(&gr, &[])?.to_graph(&gr)?.unwrap();
+        assert!(!mst.is_oriented());
+        assert_eq!(mst.get_nodes(), gr.get_nodes());
+        assert_eq!(mst.get_edges().len(), 2 * (gr.get_nodes().len() - 1));
+
+        let gr = Graph::from_file("graphs/tests/task2/task21.gr".to_string())?;
+        let condensed = Task::StronglyConnected
+            .run(&gr, &[])?
+            .to_graph(&gr)?
+            .unwrap();
+        assert_eq!(condensed.get_nodes(), vec!["{A B C}", "{D E F}", "{G H}"]);
+        let al = condensed.get_adjacency_list();
+        assert!(al["{A B C}"].contains_key("{D E F}"));
+        assert!(al["{A B C}"].contains_key("{G H}"));
+        assert!(al["{D E F}"].contains_key("{G H}"));
+        assert_eq!(condensed.get_edges().len(), 3);
+
+        assert!(Task::Dfs
+            .run(&gr, &["A".to_string()])?
+            .to_graph(&gr)?
+            .is_none());
+        Ok(())
+    }
+}